@@ -2,8 +2,47 @@ pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
 
 pub const MAX_NAME_LENGTH: usize = 128;
 pub const MAX_REASON_LENGTH: usize = 1024;
-pub const MAX_BUCKY_BANK_ID_ARRAY_LENGTH: usize = 10;
+pub const BUCKY_BANK_INDEX_PAGE_SIZE: usize = 10; // 用户存钱罐索引每页容量
+pub const MAX_ALLOWED_DEPOSITORS: usize = 10;
+pub const MAX_GIFT_MESSAGE_LENGTH: usize = 140;
+pub const MAX_GUARDIANS: usize = 5;
+pub const MAX_MILESTONES: usize = 5;
+pub const MAX_MILESTONE_LABEL_LENGTH: usize = 32;
+
+/// 配捐比例以基点计，最高 1:1
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const MAX_MATCH_RATIO_BPS: u16 = 10_000;
+/// 年利率上限（基点）
+pub const MAX_INTEREST_RATE_BPS: u16 = 10_000;
+/// 锁定期内紧急取款的罚金比例上限（基点）
+pub const MAX_EMERGENCY_PENALTY_BPS: u16 = 5_000;
+/// 利息不足最小单位的部分按此精度结转到下次计息
+pub const INTEREST_CARRY_SCALE: u128 = 1_000_000_000;
+
+/// 自动批准额度的统计窗口（毫秒）
+pub const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+pub const WEEK_MS: u64 = 7 * DAY_MS;
+/// 取款请求默认有效期及家长可设置的上限（天）
+pub const DEFAULT_WITHDRAWAL_REQUEST_TTL_DAYS: u64 = 7;
+pub const MAX_WITHDRAWAL_REQUEST_TTL_DAYS: u64 = 90;
+/// 连续存款徽章所需的周数
+pub const STREAK_BADGE_WEEKS: u32 = 4;
+/// 计息按 365 天一年
+pub const YEAR_MS: u64 = 365 * DAY_MS;
+
+/// 原生 SOL 的精度
+pub const NATIVE_SOL_DECIMALS: u8 = 9;
+/// 原生 SOL 存钱罐默认的最低存款额（0.01 SOL），程序初始化时写入全局配置
+pub const DEFAULT_MIN_NATIVE_DEPOSIT: u64 = 10_000_000;
 
 pub const BANK_GLOBAL_STATS_SEED: &[u8] = b"global_stats";
+pub const BUCKY_BANK_SEED: &[u8] = b"bucky_bank";
 pub const USER_BUCKY_BANKS_SEED: &[u8] = b"user_bucky_banks";
-pub const WITHDRAWAL_REQUEST_SEED: &[u8] = b"withdrawal_request";
\ No newline at end of file
+pub const CHILD_BUCKY_BANKS_SEED: &[u8] = b"child_bucky_banks";
+pub const CHILD_ACHIEVEMENTS_SEED: &[u8] = b"child_achievements";
+pub const BUCKY_BANK_INDEX_PAGE_SEED: &[u8] = b"bucky_bank_index_page";
+pub const WITHDRAWAL_REQUEST_SEED: &[u8] = b"withdrawal_request";
+pub const VAULT_SEED: &[u8] = b"vault";
+pub const REWARD_VAULT_SEED: &[u8] = b"reward_vault";
+pub const MATCH_VAULT_SEED: &[u8] = b"match_vault";
+pub const INTEREST_VAULT_SEED: &[u8] = b"interest_vault";
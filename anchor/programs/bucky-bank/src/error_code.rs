@@ -2,8 +2,10 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum BuckyBankError {
-    #[msg("Deposit amount must be at least 0.01 SOL")]
+    #[msg("存款金额低于存钱罐的最低存款额")]
     DepositTooSmall,
+    #[msg("存款金额超过存钱罐的单笔存款上限")]
+    DepositTooLarge,
     #[msg("Insufficient funds for withdrawal")]
     InsufficientFunds,
     #[msg("存钱罐名称无效")]
@@ -32,4 +34,150 @@ pub enum BuckyBankError {
     InvalidRequestStatus,
     #[msg("取款请求不存在")]
     RequestNotFound,
-}
\ No newline at end of file
+    #[msg("取款请求已处于待审批状态")]
+    RequestAlreadyPending,
+    #[msg("取款请求尚未批准，无法提取")]
+    RequestNotApproved,
+    #[msg("已批准的取款请求不能退回待审批状态")]
+    ApprovedRequestCannotRevert,
+    #[msg("取款请求已被批准")]
+    RequestAlreadyApproved,
+    #[msg("已批准的取款请求不能再被拒绝")]
+    ApprovedRequestCannotBeRejected,
+    #[msg("已拒绝的取款请求不能重新打开")]
+    RejectedRequestCannotReopen,
+    #[msg("已拒绝的取款请求不能再被批准")]
+    RejectedRequestCannotBeApproved,
+    #[msg("取款请求已被拒绝")]
+    RequestAlreadyRejected,
+    #[msg("已拒绝的取款请求不能提取")]
+    RejectedRequestCannotBeWithdrawn,
+    #[msg("已完成的取款请求不能重新打开")]
+    CompletedRequestCannotReopen,
+    #[msg("已完成的取款请求不能再被批准")]
+    CompletedRequestCannotBeApproved,
+    #[msg("已完成的取款请求不能再被拒绝")]
+    CompletedRequestCannotBeRejected,
+    #[msg("取款请求已完成提取")]
+    RequestAlreadyCompleted,
+    #[msg("已拒绝的取款请求不能取消")]
+    RejectedRequestCannotBeCancelled,
+    #[msg("已完成的取款请求不能取消")]
+    CompletedRequestCannotBeCancelled,
+    #[msg("已取消的取款请求不能重新打开")]
+    CancelledRequestCannotReopen,
+    #[msg("已取消的取款请求不能再被批准")]
+    CancelledRequestCannotBeApproved,
+    #[msg("已取消的取款请求不能再被拒绝")]
+    CancelledRequestCannotBeRejected,
+    #[msg("已取消的取款请求不能提取")]
+    CancelledRequestCannotBeWithdrawn,
+    #[msg("取款请求已取消")]
+    RequestAlreadyCancelled,
+    #[msg("已批准的取款请求不会过期")]
+    ApprovedRequestCannotExpire,
+    #[msg("已拒绝的取款请求不会过期")]
+    RejectedRequestCannotExpire,
+    #[msg("已完成的取款请求不会过期")]
+    CompletedRequestCannotExpire,
+    #[msg("已取消的取款请求不会过期")]
+    CancelledRequestCannotExpire,
+    #[msg("已过期的取款请求不能重新打开")]
+    ExpiredRequestCannotReopen,
+    #[msg("已过期的取款请求不能再被批准")]
+    ExpiredRequestCannotBeApproved,
+    #[msg("已过期的取款请求不能再被拒绝")]
+    ExpiredRequestCannotBeRejected,
+    #[msg("已过期的取款请求不能提取")]
+    ExpiredRequestCannotBeWithdrawn,
+    #[msg("已过期的取款请求不能取消")]
+    ExpiredRequestCannotBeCancelled,
+    #[msg("取款请求已过期")]
+    RequestAlreadyExpired,
+    #[msg("取款请求已超过有效期")]
+    RequestExpired,
+    #[msg("取款请求尚未到期")]
+    RequestNotExpired,
+    #[msg("存款币种与存钱罐不匹配")]
+    InvalidMint,
+    #[msg("缺少 Token 相关账户")]
+    MissingTokenAccounts,
+    #[msg("存钱罐尚未完成目标")]
+    BankNotCompleted,
+    #[msg("没有可领取的奖励")]
+    NoRewardToClaim,
+    #[msg("奖励已领取")]
+    RewardAlreadyClaimed,
+    #[msg("存钱罐尚未到截止时间")]
+    DeadlineNotReached,
+    #[msg("存钱罐已过截止时间")]
+    DeadlinePassed,
+    #[msg("存钱罐仍在进行中，不能关闭")]
+    BankStillActive,
+    #[msg("仍有未结束的取款请求，不能关闭存钱罐")]
+    RequestsStillOpen,
+    #[msg("收款 Token 账户不属于收款人")]
+    InvalidRecipient,
+    #[msg("该地址不允许向此存钱罐存款")]
+    DepositorNotAllowed,
+    #[msg("存款留言过长")]
+    GiftMessageTooLong,
+    #[msg("存款白名单数量超出上限")]
+    TooManyDepositors,
+    #[msg("只有监护人可以审批取款请求")]
+    NotGuardian,
+    #[msg("该监护人已投票")]
+    AlreadyVoted,
+    #[msg("监护人列表无效")]
+    InvalidGuardians,
+    #[msg("审批门槛无效")]
+    InvalidApprovalThreshold,
+    #[msg("已失败的存钱罐不能修改配置")]
+    BankFailed,
+    #[msg("没有待接受的家长转移，或签名者不是被提名的新家长")]
+    NotPendingParent,
+    #[msg("新地址无效")]
+    InvalidNewAddress,
+    #[msg("程序已暂停")]
+    ProgramPaused,
+    #[msg("只有管理员可以执行此操作")]
+    NotAdmin,
+    #[msg("没有待接受的管理员转移，或签名者不是被提名的新管理员")]
+    NotPendingAdmin,
+    #[msg("只有程序升级权限地址可以初始化")]
+    NotUpgradeAuthority,
+    #[msg("配捐比例无效")]
+    InvalidMatchRatio,
+    #[msg("配捐周期无效")]
+    InvalidMatchPeriod,
+    #[msg("年利率无效")]
+    InvalidInterestRate,
+    #[msg("里程碑无效")]
+    InvalidMilestones,
+    #[msg("取款请求有效期无效")]
+    InvalidWithdrawalRequestTtl,
+    #[msg("批准金额无效，必须大于 0 且不超过请求金额")]
+    InvalidApprovedAmount,
+    #[msg("存款限额无效，最低存款额必须大于 0 且不超过上限")]
+    InvalidDepositLimits,
+    #[msg("存钱罐处于锁定期，截止时间前只能提交紧急取款请求")]
+    BankTimeLocked,
+    #[msg("紧急取款罚金比例无效")]
+    InvalidPenaltyRate,
+    #[msg("缺少罚金收款账户")]
+    MissingPenaltyRecipient,
+    #[msg("存钱罐已冻结")]
+    BankFrozen,
+    #[msg("存钱罐未冻结")]
+    BankNotFrozen,
+    #[msg("只有已拒绝的取款请求可以关闭")]
+    RequestNotRejected,
+    #[msg("目标金额不能低于已设置的里程碑金额")]
+    TargetBelowMilestone,
+    #[msg("全局配置账户已是当前布局，无需迁移")]
+    AlreadyMigrated,
+    #[msg("全局配置账户数据无效")]
+    InvalidGlobalStatsAccount,
+    #[msg("旧版本账户数据无效")]
+    InvalidLegacyAccount,
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::Pubkey;
 use anchor_lang::prelude::*;
 
+use crate::state::{AchievementBadge, BuckyBankStatus, DepositorPolicy, LockMode, Milestone};
+
 #[event]
 pub struct BuckyBankCreated {
     pub bucky_bank_id: Pubkey,
@@ -12,6 +14,10 @@ pub struct BuckyBankCreated {
     pub deadline_ms: u64,
     pub duration_days: u64,
     pub current_balance: u64,
+    pub mint: Option<Pubkey>, // None 表示原生 SOL
+    pub decimals: u8,
+    pub reward_amount: u64,
+    pub bank_nonce: u64, // 与 parent 一起决定存钱罐地址
 }
 
 #[event]
@@ -20,6 +26,9 @@ pub struct DepositMade {
     pub amount: u64,
     pub depositor: Pubkey,
     pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+    pub message: Option<String>,
 }
 
 #[event]
@@ -29,16 +38,22 @@ pub struct EventWithdrawalRequested {
     pub amount: u64,
     pub requester: Pubkey,
     pub reason: String,
-    pub status: u8, // 0-Pending, 1-Approved, 2-Rejected, 3-Completed
+    pub status: u8, // 0-Pending, 1-Approved, 2-Rejected, 3-Completed, 4-Cancelled, 5-Expired
     pub approved_by: Pubkey,
     pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+    pub auto_approved: bool,
+    pub expires_at_ms: u64,
+    pub emergency: bool,
 }
 
 #[event]
 pub struct EventWithdrawalApproved {
     pub request_id: Pubkey,
     pub bucky_bank_id: Pubkey,
-    pub amount: u64,
+    pub requested_amount: u64,
+    pub approved_amount: u64,
     pub approved_by: Pubkey,
     pub requester: Pubkey,
     pub reason: String,
@@ -60,8 +75,396 @@ pub struct EventWithdrawalRejected {
 pub struct EventWithdrawalCompleted {
     pub request_id: Pubkey,
     pub bucky_bank_id: Pubkey,
-    pub amount: u64,
+    pub amount: u64, // 从存钱罐扣除的金额，含罚金
     pub left_balance: u64,
     pub withdrawer: Pubkey,
     pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+    pub penalty_amount: u64, // 紧急取款罚金，孩子实际收到 amount - penalty_amount
+    pub penalty_recipient: Option<Pubkey>,
+}
+
+#[event]
+pub struct EventWithdrawalCancelled {
+    pub request_id: Pubkey,
+    pub bucky_bank_id: Pubkey,
+    pub amount: u64,
+    pub requester: Pubkey,
+    pub previous_status: u8, // 取消前的状态：0-Pending, 1-Approved
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct WithdrawalRequestExpired {
+    pub request_id: Pubkey,
+    pub bucky_bank_id: Pubkey,
+    pub amount: u64,
+    pub requester: Pubkey,
+    pub expires_at_ms: u64,
+    pub expired_by: Pubkey,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct WithdrawalRequestTtlUpdated {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub withdrawal_request_ttl_ms: u64,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct BalanceReconciled {
+    pub bucky_bank_id: Pubkey,
+    pub previous_balance: u64,
+    pub current_balance: u64,
+    pub synced_by: Pubkey,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct SavingRewardUpdated {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub old_reward_amount: u64,
+    pub new_reward_amount: u64,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct RewardClaimed {
+    pub bucky_bank_id: Pubkey,
+    pub child: Pubkey,
+    pub amount: u64,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct BuckyBankSettled {
+    pub bucky_bank_id: Pubkey,
+    pub status: BuckyBankStatus,
+    pub current_balance: u64,
+    pub target_amount: u64,
+    pub deadline_ms: u64,
+    pub settled_by: Pubkey,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct BuckyBankClosed {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub status: BuckyBankStatus,
+    pub recipient: Pubkey,
+    pub payout_amount: u64,
+    pub reward_payout_amount: u64,
+    pub match_refund_amount: u64,
+    pub interest_refund_amount: u64,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct DepositorPolicyUpdated {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub depositor_policy: DepositorPolicy,
+    pub allowed_depositors: Vec<Pubkey>,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct WithdrawalVoteCast {
+    pub request_id: Pubkey,
+    pub bucky_bank_id: Pubkey,
+    pub guardian: Pubkey,
+    pub approve: bool,
+    pub reason: String,
+    pub approvals: u8,
+    pub rejections: u8,
+    pub approval_threshold: u8,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct GuardiansUpdated {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub approval_threshold: u8,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct WithdrawalAutoApproved {
+    pub request_id: Pubkey,
+    pub bucky_bank_id: Pubkey,
+    pub amount: u64,
+    pub requester: Pubkey,
+    pub auto_approve_limit: u64,
+    pub daily_spent: u64,
+    pub weekly_spent: u64,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct AutoApprovalRulesUpdated {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub auto_approve_limit: u64,
+    pub daily_cap: u64,
+    pub weekly_cap: u64,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct BuckyBankConfigUpdated {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub old_name: String,
+    pub new_name: String,
+    pub old_target_amount: u64,
+    pub new_target_amount: u64,
+    pub old_deadline_ms: u64,
+    pub new_deadline_ms: u64,
+    pub old_status: BuckyBankStatus,
+    pub new_status: BuckyBankStatus,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct ParentTransferProposed {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub pending_parent: Option<Pubkey>,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct ParentTransferred {
+    pub bucky_bank_id: Pubkey,
+    pub old_parent: Pubkey,
+    pub new_parent: Pubkey,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct ChildRotated {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub old_child: Pubkey,
+    pub new_child: Pubkey,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct GlobalStatsUpdated {
+    pub bucky_bank_id: Pubkey,
+    pub total_bucky_banks: i64,
+    pub active_bucky_banks: i64,
+    pub completed_bucky_banks: i64,
+    pub failed_bucky_banks: i64,
+    pub closed_bucky_banks: i64,
+    pub total_deposits: i64,
+    pub total_deposit_amount: i64,
+    pub total_withdrawals: i64,
+    pub total_withdrawal_amount: i64,
+    pub total_value_locked: i64,
+    pub updated_at_ms: u64,
+}
+
+#[event]
+pub struct PauseStateChanged {
+    pub admin: Pubkey,
+    pub paused: bool,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct BankGlobalStatsMigrated {
+    pub admin: Pubkey,
+    pub old_len: u64,
+    pub new_len: u64,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct AdminTransferProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct MatchingRuleUpdated {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub match_ratio_bps: u16,
+    pub match_period_cap: u64,
+    pub match_period_ms: u64,
+    pub match_vault_balance: u64,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct MatchCredited {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub depositor: Pubkey,
+    pub deposit_amount: u64,
+    pub match_amount: u64, // 按比例和周期上限应配捐的数量
+    pub credited_amount: u64, // 实际从配捐金库转入的数量
+    pub skipped: bool, // 配捐金库余额不足，未能足额配捐
+    pub match_vault_balance: u64,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct InterestRateUpdated {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub interest_rate_bps: u16,
+    pub interest_vault_balance: u64,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct InterestAccrued {
+    pub bucky_bank_id: Pubkey,
+    pub interest_rate_bps: u16,
+    pub current_balance: u64, // 付息后的存钱罐余额
+    pub interest_amount: u64, // 应付利息
+    pub credited_amount: u64, // 实际从利息金库转入的数量
+    pub skipped: bool, // 利息金库余额不足，未能足额付息
+    pub interest_pending: u64, // 留待下次付息的利息
+    pub interest_vault_balance: u64,
+    pub total_interest_earned: u64,
+    pub accrued_by: Pubkey,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct MilestonesUpdated {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub milestones: Vec<Milestone>,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct MilestoneReached {
+    pub bucky_bank_id: Pubkey,
+    pub milestone_index: u8,
+    pub amount: u64, // 里程碑金额
+    pub label: String,
+    pub current_balance: u64, // 达成时的存钱罐余额
+    pub target_amount: u64,
+    pub reached_by: Pubkey,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct AchievementUnlocked {
+    pub child: Pubkey,
+    pub bucky_bank_id: Pubkey, // 触发解锁的存钱罐
+    pub badge: AchievementBadge,
+    pub current_streak_weeks: u32,
+    pub longest_streak_weeks: u32,
+    pub current_balance: u64,
+    pub target_amount: u64,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct DefaultDepositLimitsUpdated {
+    pub admin: Pubkey,
+    pub default_min_deposit: u64,
+    pub default_max_deposit: u64,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct DepositLimitsUpdated {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub min_deposit: u64,
+    pub max_deposit: u64,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct LockModeUpdated {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub lock_mode: LockMode,
+    pub emergency_penalty_bps: u16,
+    pub penalty_recipient: Pubkey, // 罚金收款地址，未设置慈善地址时为家长
+    pub deadline_ms: u64,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct BuckyBankFrozen {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub reason: String,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct BuckyBankUnfrozen {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub reason: String,
+    pub created_at_ms: u64,
+}
+
+#[event]
+pub struct EmergencyWithdrawal {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub destination: Pubkey, // 家长指定的收款地址
+    pub amount: u64,
+    pub left_balance: u64,
+    pub reason: String,
+    pub frozen: bool,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[event]
+pub struct WithdrawalRequestClosed {
+    pub request_id: Pubkey,
+    pub bucky_bank_id: Pubkey,
+    pub requester: Pubkey,
+    pub closed_by: Pubkey,
+    pub created_at_ms: u64,
 }
@@ -0,0 +1,139 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, BuckyBankStatus, GlobalStatsDelta},
+    utils::{credit_bank_vault, emit_global_stats},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct AccrueInterest<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, bucky_bank.key().as_ref()],
+        bump = bucky_bank.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [INTEREST_VAULT_SEED, bucky_bank.key().as_ref()],
+        bump,
+    )]
+    pub interest_vault: SystemAccount<'info>,
+
+    /// 以下账户仅 SPL Token 存钱罐需要
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = interest_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub interest_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// 计息：按年利率把自上次计息以来的利息从利息金库转入存钱罐
+///
+/// 任何人都可以调用，只对进行中且未冻结的存钱罐计息，计息截止到截止时间。
+/// 利息金库余额不足时按余额付息，差额留待家长补足后再付。存钱罐完成或结算后不再计息，
+/// 但之前已记入的应付利息仍可继续支付。
+pub fn _accrue_interest(ctx: Context<AccrueInterest>) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(
+        bucky_bank.status == BuckyBankStatus::Active || bucky_bank.interest_pending > 0,
+        ErrorCode::BankNotActive
+    );
+    require!(!bucky_bank.frozen, ErrorCode::BankFrozen);
+
+    let (interest_amount, credited) = bucky_bank.take_interest(current_time_ms);
+    if interest_amount == 0 {
+        return Ok(());
+    }
+
+    let received = if credited > 0 {
+        credit_bank_vault(
+            INTEREST_VAULT_SEED,
+            &bucky_bank.key(),
+            ctx.bumps.interest_vault,
+            bucky_bank.config.mint,
+            &ctx.accounts.interest_vault,
+            &ctx.accounts.vault,
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.interest_vault_token_account.as_deref(),
+            ctx.accounts.vault_token_account.as_deref_mut(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+            credited,
+        )?
+    } else {
+        0
+    };
+
+    bucky_bank.current_balance = bucky_bank.current_balance
+        .checked_add(received)
+        .ok_or(ErrorCode::Overflow)?;
+    bucky_bank.total_interest_earned = bucky_bank.total_interest_earned
+        .checked_add(received)
+        .ok_or(ErrorCode::Overflow)?;
+
+    // 利息可能使存钱罐达到目标，截止时间之后交由结算判断
+    let mut stats = GlobalStatsDelta::default();
+    if bucky_bank.status == BuckyBankStatus::Active
+        && current_time_ms < bucky_bank.config.deadline_ms
+        && bucky_bank.current_balance >= bucky_bank.config.target_amount
+    {
+        bucky_bank.status = BuckyBankStatus::Completed;
+        stats.record_status_change(BuckyBankStatus::Active, BuckyBankStatus::Completed);
+    }
+    stats.record_parent_credit(received, bucky_bank.config.is_native());
+    emit_global_stats(stats, bucky_bank.key(), current_time_ms);
+
+    emit!(InterestAccrued {
+        bucky_bank_id: bucky_bank.key(),
+        interest_rate_bps: bucky_bank.interest_rate_bps,
+        current_balance: bucky_bank.current_balance,
+        interest_amount,
+        credited_amount: received,
+        skipped: credited < interest_amount,
+        interest_pending: bucky_bank.interest_pending,
+        interest_vault_balance: bucky_bank.interest_vault_balance,
+        total_interest_earned: bucky_bank.total_interest_earned,
+        accrued_by: ctx.accounts.signer.key(),
+        created_at_ms: current_time_ms,
+        mint: bucky_bank.config.mint,
+        decimals: bucky_bank.config.decimals,
+    });
+
+    Ok(())
+}
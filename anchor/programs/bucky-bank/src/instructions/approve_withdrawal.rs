@@ -1,12 +1,20 @@
 use crate::{
+    constants::*,
     error_code::BuckyBankError as ErrorCode,
     events::*,
-    state::{BuckyBankInfo, WithdrawalRequestInfo, WithdrawalStatus},
+    state::{BankGlobalStatsInfo, BuckyBankInfo, WithdrawalRequestInfo, WithdrawalStatus},
 };
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct ApproveWithdrawal<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
     #[account(mut)]
     pub bucky_bank: Account<'info, BuckyBankInfo>,
 
@@ -14,20 +22,27 @@ pub struct ApproveWithdrawal<'info> {
     pub withdrawal_request: Account<'info, WithdrawalRequestInfo>,
 
     #[account(mut)]
-    pub parent: Signer<'info>,
+    pub guardian: Signer<'info>,
 }
 
-/// 家长审批取款请求
-/// 
+/// 监护人对取款请求投票
+///
+/// 每位监护人只能投一次票，请求过期后不能再投票。紧急请求需要全部监护人批准。批准票达到门槛时请求变为已批准；
+/// 剩余未投票的监护人不足以达到门槛时请求变为已拒绝。
+///
+/// 批准时可以给出少于请求金额的批准金额（还价），最终批准金额取所有批准票中最小的金额。
+///
 /// # 参数
 /// - `approve`: true 表示批准，false 表示拒绝
 /// - `reason`: 审批原因
+/// - `approved_amount`: 批准金额，None 表示批准全部请求金额；拒绝时忽略
 pub fn _approve_withdrawal(
     ctx: Context<ApproveWithdrawal>,
     approve: bool,
     reason: String,
+    approved_amount: Option<u64>,
 ) -> Result<()> {
-    let sender = ctx.accounts.parent.key();
+    let sender = ctx.accounts.guardian.key();
     let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
 
     let bucky_bank = &mut ctx.accounts.bucky_bank;
@@ -35,8 +50,13 @@ pub fn _approve_withdrawal(
 
     // 验证权限和状态
     require!(
-        sender == bucky_bank.parent,
-        ErrorCode::NotParent
+        bucky_bank.is_guardian(&sender),
+        ErrorCode::NotGuardian
+    );
+
+    require!(
+        withdrawal_request.bucky_bank_id == bucky_bank.key(),
+        ErrorCode::RequestNotFound
     );
 
     require!(
@@ -45,23 +65,65 @@ pub fn _approve_withdrawal(
     );
 
     require!(
-        withdrawal_request.bucky_bank_id == bucky_bank.key(),
-        ErrorCode::RequestNotFound
+        !withdrawal_request.is_expired(current_time_ms),
+        ErrorCode::RequestExpired
+    );
+
+    require!(
+        !withdrawal_request.has_voted(&sender),
+        ErrorCode::AlreadyVoted
     );
 
     let request_id = withdrawal_request.key();
-    let amount = withdrawal_request.amount;
+    let requested_amount = withdrawal_request.requested_amount;
     let requester = withdrawal_request.requester;
+    let threshold = withdrawal_request
+        .required_approvals(bucky_bank.guardians.len(), bucky_bank.approval_threshold);
 
+    // 记录投票，批准金额取所有批准票中最小的金额
     if approve {
+        let vote_amount = approved_amount.unwrap_or(requested_amount);
+        require!(
+            vote_amount > 0 && vote_amount <= requested_amount,
+            ErrorCode::InvalidApprovedAmount
+        );
+        withdrawal_request.approved_amount = if withdrawal_request.approvals.is_empty() {
+            vote_amount
+        } else {
+            withdrawal_request.approved_amount.min(vote_amount)
+        };
+        withdrawal_request.approvals.push(sender);
+    } else {
+        withdrawal_request.rejections.push(sender);
+    }
+
+    emit!(WithdrawalVoteCast {
+        request_id,
+        bucky_bank_id: bucky_bank.key(),
+        guardian: sender,
+        approve,
+        reason: reason.clone(),
+        approvals: withdrawal_request.approvals.len() as u8,
+        rejections: withdrawal_request.rejections.len() as u8,
+        approval_threshold: threshold as u8,
+        created_at_ms: current_time_ms,
+    });
+
+    let reachable = bucky_bank
+        .guardians
+        .len()
+        .saturating_sub(withdrawal_request.rejections.len());
+
+    if withdrawal_request.approvals.len() >= threshold {
         // 检查余额是否足够提取
+        let approved_amount = withdrawal_request.approved_amount;
         require!(
-            amount <= bucky_bank.current_balance,
+            approved_amount <= bucky_bank.current_balance,
             ErrorCode::InsufficientFunds
         );
 
-        // 更新状态为已授权
-        withdrawal_request.status = WithdrawalStatus::Approved;
+        // 达到门槛，更新状态为已授权
+        withdrawal_request.transition_to(WithdrawalStatus::Approved)?;
         withdrawal_request.approved_by = sender;
         withdrawal_request.approved_at_ms = current_time_ms;
 
@@ -69,29 +131,31 @@ pub fn _approve_withdrawal(
         emit!(EventWithdrawalApproved {
             request_id,
             bucky_bank_id: bucky_bank.key(),
-            amount,
+            requested_amount,
+            approved_amount,
             approved_by: sender,
             requester,
-            reason: reason.clone(),
+            reason,
             created_at_ms: current_time_ms,
         });
-    } else {
-        // 拒绝请求
-        withdrawal_request.status = WithdrawalStatus::Rejected;
+    } else if reachable < threshold {
+        // 已无法达到门槛，拒绝请求
+        withdrawal_request.transition_to(WithdrawalStatus::Rejected)?;
         withdrawal_request.approved_by = sender;
         withdrawal_request.approved_at_ms = current_time_ms;
+        bucky_bank.open_request_count = bucky_bank.open_request_count.saturating_sub(1);
 
         // 发送事件
         emit!(EventWithdrawalRejected {
             request_id,
             bucky_bank_id: bucky_bank.key(),
-            amount,
+            amount: requested_amount,
             requester,
             rejected_by: sender,
-            reason: reason.clone(),
+            reason,
             created_at_ms: current_time_ms,
         });
-    };
+    }
 
     Ok(())
 }
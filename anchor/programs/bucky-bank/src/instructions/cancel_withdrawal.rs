@@ -0,0 +1,75 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, WithdrawalRequestInfo, WithdrawalStatus},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    /// 取消后关闭请求账户，租金退还给孩子
+    #[account(mut, close = child)]
+    pub withdrawal_request: Account<'info, WithdrawalRequestInfo>,
+
+    #[account(mut)]
+    pub child: Signer<'info>,
+}
+
+/// 孩子取消取款请求
+///
+/// 待审批或已批准但尚未提取的请求可以取消，取消后请求账户关闭并退还租金。
+/// 自动批准的请求取消后退回占用的自动批准额度。
+pub fn _cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+    let sender = ctx.accounts.child.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+    let withdrawal_request = &mut ctx.accounts.withdrawal_request;
+
+    require!(
+        sender == bucky_bank.config.child_address,
+        ErrorCode::NotChild
+    );
+    require!(
+        withdrawal_request.requester == sender,
+        ErrorCode::NotChildForWithdrawal
+    );
+    require!(
+        withdrawal_request.bucky_bank_id == bucky_bank.key(),
+        ErrorCode::RequestNotFound
+    );
+
+    let previous_status = withdrawal_request.status;
+    withdrawal_request.transition_to(WithdrawalStatus::Cancelled)?;
+
+    // 自动批准的请求未提取就取消，退回占用的日、周额度
+    if previous_status == WithdrawalStatus::Approved && withdrawal_request.auto_approved {
+        bucky_bank.release_auto_approval(
+            withdrawal_request.approved_amount,
+            withdrawal_request.approved_at_ms,
+        );
+    }
+    bucky_bank.open_request_count = bucky_bank.open_request_count.saturating_sub(1);
+
+    emit!(EventWithdrawalCancelled {
+        request_id: withdrawal_request.key(),
+        bucky_bank_id: bucky_bank.key(),
+        amount: withdrawal_request.requested_amount,
+        requester: sender,
+        previous_status: previous_status as u8,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, BuckyBankStatus},
+    utils::payout_from_vault,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct ClaimSavingRewards<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    #[account(mut)]
+    pub child: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_VAULT_SEED, bucky_bank.key().as_ref()],
+        bump = bucky_bank.reward_vault_bump,
+    )]
+    pub reward_vault: SystemAccount<'info>,
+
+    /// 以下账户仅 SPL Token 存钱罐需要
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = reward_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = child,
+        associated_token::mint = mint,
+        associated_token::authority = child,
+        associated_token::token_program = token_program,
+    )]
+    pub child_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// 孩子领取达成目标奖励
+///
+/// 存钱罐状态为已完成时可领取一次，奖励从奖励金库转给孩子。
+pub fn _claim_saving_rewards(ctx: Context<ClaimSavingRewards>) -> Result<()> {
+    let sender = ctx.accounts.child.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(
+        sender == bucky_bank.config.child_address,
+        ErrorCode::NotChild
+    );
+    require!(
+        bucky_bank.status == BuckyBankStatus::Completed,
+        ErrorCode::BankNotCompleted
+    );
+    require!(!bucky_bank.reward_claimed, ErrorCode::RewardAlreadyClaimed);
+    require!(bucky_bank.reward_amount > 0, ErrorCode::NoRewardToClaim);
+
+    let amount = bucky_bank.reward_amount;
+    bucky_bank.reward_claimed = true;
+
+    payout_from_vault(
+        REWARD_VAULT_SEED,
+        &bucky_bank.key(),
+        bucky_bank.reward_vault_bump,
+        bucky_bank.config.mint,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.child.to_account_info(),
+        ctx.accounts.mint.as_deref(),
+        ctx.accounts.reward_vault_token_account.as_deref(),
+        ctx.accounts.child_token_account.as_deref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+        amount,
+    )?;
+
+    emit!(RewardClaimed {
+        bucky_bank_id: bucky_bank.key(),
+        child: sender,
+        amount,
+        created_at_ms: current_time_ms,
+        mint: bucky_bank.config.mint,
+        decimals: bucky_bank.config.decimals,
+    });
+
+    Ok(())
+}
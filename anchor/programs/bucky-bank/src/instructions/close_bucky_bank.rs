@@ -0,0 +1,290 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, BuckyBankStatus, GlobalStatsDelta, UserBuckyBanksInfo, UserBuckyBanksPage},
+    utils::{emit_global_stats, sweep_vault},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct CloseBuckyBank<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(
+        mut,
+        close = parent,
+        has_one = parent @ ErrorCode::NotParent,
+    )]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    #[account(
+        mut,
+        seeds = [USER_BUCKY_BANKS_SEED, parent.key().as_ref()],
+        bump,
+    )]
+    pub user_bucky_banks: Box<Account<'info, UserBuckyBanksInfo>>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKY_BANK_INDEX_PAGE_SEED,
+            user_bucky_banks.key().as_ref(),
+            bucky_bank.parent_index_page.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_bucky_banks_page: Box<Account<'info, UserBuckyBanksPage>>,
+
+    #[account(
+        mut,
+        seeds = [CHILD_BUCKY_BANKS_SEED, bucky_bank.config.child_address.as_ref()],
+        bump,
+    )]
+    pub child_bucky_banks: Box<Account<'info, UserBuckyBanksInfo>>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKY_BANK_INDEX_PAGE_SEED,
+            child_bucky_banks.key().as_ref(),
+            bucky_bank.child_index_page.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub child_bucky_banks_page: Box<Account<'info, UserBuckyBanksPage>>,
+
+    #[account(mut)]
+    pub parent: Signer<'info>,
+
+    /// 存钱罐已完成时的收款人
+    #[account(
+        mut,
+        address = bucky_bank.config.child_address @ ErrorCode::NotChild,
+    )]
+    pub child: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, bucky_bank.key().as_ref()],
+        bump = bucky_bank.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_VAULT_SEED, bucky_bank.key().as_ref()],
+        bump = bucky_bank.reward_vault_bump,
+    )]
+    pub reward_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [MATCH_VAULT_SEED, bucky_bank.key().as_ref()],
+        bump,
+    )]
+    pub match_vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [INTEREST_VAULT_SEED, bucky_bank.key().as_ref()],
+        bump,
+    )]
+    pub interest_vault: SystemAccount<'info>,
+
+    /// 以下账户仅 SPL Token 存钱罐需要
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = reward_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 配捐金库 Token 账户，SPL Token 存钱罐开设配捐后需要
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = match_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub match_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 利息金库 Token 账户，SPL Token 存钱罐开设计息后需要
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = interest_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub interest_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 家长的 Token 账户，SPL Token 存钱罐退还剩余配捐和利息资金时需要
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = parent,
+        token::token_program = token_program,
+    )]
+    pub parent_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 收款人的 Token 账户：已完成时属于孩子，已失败时属于家长
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub recipient_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// 关闭存钱罐并回收租金
+///
+/// 只有家长可以在存钱罐结束后关闭。剩余存款与未领取的奖励按状态付出：
+/// 已完成付给孩子，已失败退回家长；剩余配捐和利息资金退还家长。金库与账户租金退还家长，
+/// 并从家长的存钱罐列表中移除。仍有未结束的取款请求时不能关闭。
+pub fn _close_bucky_bank(ctx: Context<CloseBuckyBank>) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+    let bucky_bank_id = bucky_bank.key();
+
+    require!(
+        bucky_bank.open_request_count == 0,
+        ErrorCode::RequestsStillOpen
+    );
+
+    let recipient = match bucky_bank.status {
+        BuckyBankStatus::Completed => ctx.accounts.child.to_account_info(),
+        BuckyBankStatus::Failed => ctx.accounts.parent.to_account_info(),
+        BuckyBankStatus::Active => return err!(ErrorCode::BankStillActive),
+    };
+    let parent = ctx.accounts.parent.to_account_info();
+
+    let payout_amount = sweep_vault(
+        VAULT_SEED,
+        &bucky_bank_id,
+        bucky_bank.vault_bump,
+        bucky_bank.config.mint,
+        &ctx.accounts.vault,
+        &recipient,
+        &parent,
+        ctx.accounts.mint.as_deref(),
+        ctx.accounts.vault_token_account.as_deref(),
+        ctx.accounts.recipient_token_account.as_deref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+    )?;
+
+    // 未领取的奖励与存款同一去向
+    let reward_payout_amount = sweep_vault(
+        REWARD_VAULT_SEED,
+        &bucky_bank_id,
+        bucky_bank.reward_vault_bump,
+        bucky_bank.config.mint,
+        &ctx.accounts.reward_vault,
+        &recipient,
+        &parent,
+        ctx.accounts.mint.as_deref(),
+        ctx.accounts.reward_vault_token_account.as_deref(),
+        ctx.accounts.recipient_token_account.as_deref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+    )?;
+
+    // 剩余配捐资金属于家长，始终退还家长
+    let match_refund_amount = if bucky_bank.match_vault_initialized {
+        sweep_vault(
+            MATCH_VAULT_SEED,
+            &bucky_bank_id,
+            ctx.bumps.match_vault,
+            bucky_bank.config.mint,
+            &ctx.accounts.match_vault,
+            &parent,
+            &parent,
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.match_vault_token_account.as_deref(),
+            ctx.accounts.parent_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+        )?
+    } else {
+        0
+    };
+    bucky_bank.match_vault_balance = 0;
+
+    // 剩余利息资金同样退还家长
+    let interest_refund_amount = if bucky_bank.interest_vault_initialized {
+        sweep_vault(
+            INTEREST_VAULT_SEED,
+            &bucky_bank_id,
+            ctx.bumps.interest_vault,
+            bucky_bank.config.mint,
+            &ctx.accounts.interest_vault,
+            &parent,
+            &parent,
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.interest_vault_token_account.as_deref(),
+            ctx.accounts.parent_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+        )?
+    } else {
+        0
+    };
+    bucky_bank.interest_vault_balance = 0;
+
+    // 发出全局统计增量，锁定总额按关闭前的记账余额扣减
+    let mut stats = GlobalStatsDelta::default();
+    stats.record_bank_closed(
+        bucky_bank.status,
+        bucky_bank.current_balance,
+        bucky_bank.config.is_native(),
+    );
+    emit_global_stats(stats, bucky_bank.key(), current_time_ms);
+
+    bucky_bank.current_balance = 0;
+
+    // 从家长索引和孩子索引中移除
+    ctx.accounts
+        .user_bucky_banks
+        .remove(&mut ctx.accounts.user_bucky_banks_page, bucky_bank_id);
+    ctx.accounts
+        .child_bucky_banks
+        .remove(&mut ctx.accounts.child_bucky_banks_page, bucky_bank_id);
+
+    emit!(BuckyBankClosed {
+        bucky_bank_id,
+        parent: bucky_bank.parent,
+        status: bucky_bank.status,
+        recipient: recipient.key(),
+        payout_amount,
+        reward_payout_amount,
+        match_refund_amount,
+        interest_refund_amount,
+        created_at_ms: current_time_ms,
+        mint: bucky_bank.config.mint,
+        decimals: bucky_bank.config.decimals,
+    });
+
+    Ok(())
+}
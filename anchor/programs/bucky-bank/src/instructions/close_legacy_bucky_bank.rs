@@ -0,0 +1,80 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, LegacyBuckyBankInfo},
+    utils::close_program_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+
+#[derive(Accounts)]
+pub struct CloseLegacyBuckyBank<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    /// CHECK: 旧布局的存钱罐无法按当前结构反序列化，在指令中校验判别符、长度和家长
+    #[account(mut, owner = crate::ID)]
+    pub bucky_bank: UncheckedAccount<'info>,
+
+    /// 存款转给孩子
+    #[account(mut)]
+    pub child: SystemAccount<'info>,
+
+    /// 租金退还给家长
+    #[account(mut)]
+    pub parent: Signer<'info>,
+}
+
+/// 家长关闭旧版本创建的存钱罐
+///
+/// 旧版本把存款以 lamports 直接存放在存钱罐账户上，当前布局无法读取这些账户。
+/// 超出免租金额的部分作为存款转给孩子，免租金额退还家长，然后关闭账户。
+pub fn _close_legacy_bucky_bank(ctx: Context<CloseLegacyBuckyBank>) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = ctx.accounts.bucky_bank.to_account_info();
+    let child = ctx.accounts.child.to_account_info();
+    let parent = ctx.accounts.parent.to_account_info();
+
+    let legacy = {
+        let data = bucky_bank.try_borrow_data()?;
+        require!(
+            data.len() == LegacyBuckyBankInfo::LEN
+                && data[..ANCHOR_DISCRIMINATOR_SIZE] == *BuckyBankInfo::DISCRIMINATOR,
+            ErrorCode::InvalidLegacyAccount
+        );
+        LegacyBuckyBankInfo::deserialize(&mut &data[ANCHOR_DISCRIMINATOR_SIZE..])?
+    };
+
+    require_keys_eq!(legacy.parent, parent.key(), ErrorCode::NotParent);
+    require_keys_eq!(legacy.config.child_address, child.key(), ErrorCode::NotChild);
+
+    // 超出免租金额的 lamports 都是孩子的存款
+    let rent = Rent::get()?.minimum_balance(bucky_bank.data_len());
+    let payout_amount = bucky_bank.lamports().saturating_sub(rent);
+    if payout_amount > 0 {
+        **bucky_bank.try_borrow_mut_lamports()? -= payout_amount;
+        **child.try_borrow_mut_lamports()? += payout_amount;
+    }
+    close_program_account(&bucky_bank, &parent)?;
+
+    emit!(BuckyBankClosed {
+        bucky_bank_id: bucky_bank.key(),
+        parent: parent.key(),
+        status: legacy.status,
+        recipient: child.key(),
+        payout_amount,
+        reward_payout_amount: 0,
+        match_refund_amount: 0,
+        interest_refund_amount: 0,
+        created_at_ms: current_time_ms,
+        mint: None,
+        decimals: NATIVE_SOL_DECIMALS,
+    });
+
+    Ok(())
+}
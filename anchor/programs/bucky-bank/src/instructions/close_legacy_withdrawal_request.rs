@@ -0,0 +1,63 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, LegacyWithdrawalRequestInfo, WithdrawalRequestInfo},
+    utils::close_program_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+
+#[derive(Accounts)]
+pub struct CloseLegacyWithdrawalRequest<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    /// CHECK: 旧布局的取款请求无法按当前结构反序列化，在指令中校验判别符、长度和请求者
+    #[account(mut, owner = crate::ID)]
+    pub withdrawal_request: UncheckedAccount<'info>,
+
+    /// 租金退还给请求者
+    #[account(mut)]
+    pub requester: SystemAccount<'info>,
+
+    pub signer: Signer<'info>,
+}
+
+/// 关闭旧版本创建的取款请求
+///
+/// 任何人都可以调用。旧请求已无法审批或提取，关闭账户并将租金退还请求者；
+/// 请求对应的存款随 `close_legacy_bucky_bank` 转给孩子。
+pub fn _close_legacy_withdrawal_request(ctx: Context<CloseLegacyWithdrawalRequest>) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let withdrawal_request = ctx.accounts.withdrawal_request.to_account_info();
+    let requester = ctx.accounts.requester.to_account_info();
+
+    let legacy = {
+        let data = withdrawal_request.try_borrow_data()?;
+        require!(
+            data.len() == LegacyWithdrawalRequestInfo::LEN
+                && data[..ANCHOR_DISCRIMINATOR_SIZE] == *WithdrawalRequestInfo::DISCRIMINATOR,
+            ErrorCode::InvalidLegacyAccount
+        );
+        LegacyWithdrawalRequestInfo::deserialize(&mut &data[ANCHOR_DISCRIMINATOR_SIZE..])?
+    };
+
+    require_keys_eq!(legacy.requester, requester.key(), ErrorCode::NotChildForWithdrawal);
+
+    close_program_account(&withdrawal_request, &requester)?;
+
+    emit!(WithdrawalRequestClosed {
+        request_id: withdrawal_request.key(),
+        bucky_bank_id: legacy.bucky_bank_id,
+        requester: legacy.requester,
+        closed_by: ctx.accounts.signer.key(),
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, WithdrawalRequestInfo, WithdrawalStatus},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseWithdrawalRequest<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    /// 关闭请求账户，租金退还给请求者
+    #[account(mut, close = requester)]
+    pub withdrawal_request: Account<'info, WithdrawalRequestInfo>,
+
+    #[account(
+        mut,
+        address = withdrawal_request.requester @ ErrorCode::NotChildForWithdrawal,
+    )]
+    pub requester: SystemAccount<'info>,
+
+    pub signer: Signer<'info>,
+}
+
+/// 关闭已拒绝的取款请求
+///
+/// 任何人都可以调用。已拒绝的请求不会再变化，关闭账户并将租金退还请求者。
+pub fn _close_withdrawal_request(ctx: Context<CloseWithdrawalRequest>) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let withdrawal_request = &ctx.accounts.withdrawal_request;
+
+    require!(
+        withdrawal_request.status == WithdrawalStatus::Rejected,
+        ErrorCode::RequestNotRejected
+    );
+
+    emit!(WithdrawalRequestClosed {
+        request_id: withdrawal_request.key(),
+        bucky_bank_id: withdrawal_request.bucky_bank_id,
+        requester: withdrawal_request.requester,
+        closed_by: ctx.accounts.signer.key(),
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
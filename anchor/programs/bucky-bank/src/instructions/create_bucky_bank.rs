@@ -2,35 +2,138 @@ use crate::{
     constants::*,
     error_code::BuckyBankError as ErrorCode,
     events::*,
-    state::{BuckyBankInfo, BankGlobalStatsInfo, UserBuckyBanksInfo, BuckyBankStatus, Config},
+    state::{BuckyBankInfo, BankGlobalStatsInfo, UserBuckyBanksInfo, UserBuckyBanksPage, BuckyBankStatus, Config, DepositorPolicy, GlobalStatsDelta, LockMode},
+    utils::{deposit_into_vault, emit_global_stats, fund_rent_exempt_minimum},
 };
 use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
 
 #[derive(Accounts)]
+#[instruction(name: String, target_amount: u64, duration_days: u64, child_address: Pubkey)]
 pub struct CreateBuckyBank<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
-    #[account(mut)]
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
     pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
 
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR_SIZE + UserBuckyBanksInfo::INIT_SPACE,
+        seeds = [USER_BUCKY_BANKS_SEED, owner.key().as_ref()],
+        bump,
+    )]
+    pub user_bucky_banks: Box<Account<'info, UserBuckyBanksInfo>>,
+
+    /// 存钱罐地址由家长地址和家长的存钱罐 nonce 决定，见 `BuckyBankInfo::derive_address`
     #[account(
         init,
         payer = owner,
         space = ANCHOR_DISCRIMINATOR_SIZE + BuckyBankInfo::INIT_SPACE,
-        seeds = [bank_global_stats.total_bucky_banks.to_le_bytes().as_ref()],
+        seeds = [
+            BUCKY_BANK_SEED,
+            owner.key().as_ref(),
+            user_bucky_banks.bank_nonce.to_le_bytes().as_ref(),
+        ],
         bump,
     )]
     pub bucky_bank: Account<'info, BuckyBankInfo>,
 
+    /// 家长索引当前写入页
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR_SIZE + UserBuckyBanksPage::INIT_SPACE,
+        seeds = [
+            BUCKY_BANK_INDEX_PAGE_SEED,
+            user_bucky_banks.key().as_ref(),
+            user_bucky_banks.current_page.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_bucky_banks_page: Box<Account<'info, UserBuckyBanksPage>>,
+
+    /// 孩子索引，孩子可据此查找自己所属的存钱罐
     #[account(
         init_if_needed,
         payer = owner,
         space = ANCHOR_DISCRIMINATOR_SIZE + UserBuckyBanksInfo::INIT_SPACE,
-        seeds = [USER_BUCKY_BANKS_SEED, owner.key().as_ref()],
+        seeds = [CHILD_BUCKY_BANKS_SEED, child_address.as_ref()],
+        bump,
+    )]
+    pub child_bucky_banks: Box<Account<'info, UserBuckyBanksInfo>>,
+
+    /// 孩子索引当前写入页
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR_SIZE + UserBuckyBanksPage::INIT_SPACE,
+        seeds = [
+            BUCKY_BANK_INDEX_PAGE_SEED,
+            child_bucky_banks.key().as_ref(),
+            child_bucky_banks.current_page.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub child_bucky_banks_page: Box<Account<'info, UserBuckyBanksPage>>,
+
+    /// 金库 PDA：原生 SOL 存款存放于此，同时作为金库 Token 账户的 authority
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, bucky_bank.key().as_ref()],
+        bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// 存款币种，不传表示原生 SOL 存钱罐
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 奖励金库 PDA：存放家长预存的达成目标奖励
+    #[account(
+        mut,
+        seeds = [REWARD_VAULT_SEED, bucky_bank.key().as_ref()],
         bump,
     )]
-    pub user_bucky_banks: Account<'info, UserBuckyBanksInfo>,
+    pub reward_vault: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = mint,
+        associated_token::authority = reward_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 家长的 Token 账户，SPL Token 存钱罐预存奖励时需要
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+        token::token_program = token_program,
+    )]
+    pub owner_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
 
     pub system_program: Program<'info, System>,
 }
@@ -42,6 +145,7 @@ pub fn _create_bucky_bank(
     target_amount: u64,
     duration_days: u64,
     child_address: Pubkey,
+    reward_amount: u64,
 ) -> Result<()> {
     // 验证输入
     require!(
@@ -52,16 +156,45 @@ pub fn _create_bucky_bank(
     require!(duration_days > 0, ErrorCode::InvalidDeadline);
 
     let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
-    let deadline_ms = current_time_ms + (duration_days * 24 * 60 * 60 * 1000);
+    let deadline_ms = duration_days
+        .checked_mul(DAY_MS)
+        .and_then(|duration_ms| current_time_ms.checked_add(duration_ms))
+        .ok_or(ErrorCode::InvalidDeadline)?;
 
     let sender = ctx.accounts.owner.key();
 
+    // 确定存款币种：传入 Mint 则为 SPL Token 存钱罐
+    let (mint, decimals) = match ctx.accounts.mint.as_ref() {
+        Some(mint) => {
+            require!(
+                ctx.accounts.vault_token_account.is_some()
+                    && ctx.accounts.reward_vault_token_account.is_some(),
+                ErrorCode::MissingTokenAccounts
+            );
+            (Some(mint.key()), mint.decimals)
+        }
+        None => (None, NATIVE_SOL_DECIMALS),
+    };
+
     // 创建配置
+    // 采用全局默认存款限额，SPL Token 存钱罐按 Mint 精度换算
+    let stats = &ctx.accounts.bank_global_stats;
+    let (min_deposit, max_deposit) = Config::default_deposit_limits(
+        stats.default_min_deposit,
+        stats.default_max_deposit,
+        decimals,
+    );
+
     let config = Config {
         name,
         target_amount,
         deadline_ms,
         child_address,
+        mint,
+        decimals,
+        min_deposit,
+        max_deposit,
+        lock_mode: LockMode::Flexible,
     };
 
     // 初始化存钱罐
@@ -72,24 +205,124 @@ pub fn _create_bucky_bank(
     bucky_bank.status = BuckyBankStatus::Active;
     bucky_bank.deposit_count = 0;
     bucky_bank.withdrawal_request_counter = 0;
+    bucky_bank.open_request_count = 0;
     bucky_bank.created_at_ms = current_time_ms;
     bucky_bank.last_deposit_ms = current_time_ms;
+    bucky_bank.vault_bump = ctx.bumps.vault;
 
-    // 更新全局统计
-    let stats = &mut ctx.accounts.bank_global_stats;
-    stats.total_bucky_banks = stats.total_bucky_banks.checked_add(1)
+    bucky_bank.reward_vault_bump = ctx.bumps.reward_vault;
+    bucky_bank.reward_claimed = false;
+
+    bucky_bank.depositor_policy = DepositorPolicy::ChildOnly;
+    bucky_bank.allowed_depositors = Vec::new();
+
+    // 默认由家长单独审批
+    bucky_bank.guardians = vec![sender];
+    bucky_bank.approval_threshold = 1;
+
+    // 默认关闭自动批准
+    bucky_bank.auto_approve_limit = 0;
+    bucky_bank.daily_cap = 0;
+    bucky_bank.weekly_cap = 0;
+    bucky_bank.daily_spent = 0;
+    bucky_bank.daily_window_start_ms = current_time_ms;
+    bucky_bank.weekly_spent = 0;
+    bucky_bank.weekly_window_start_ms = current_time_ms;
+
+    bucky_bank.pending_parent = None;
+
+    // 默认关闭配捐，配捐金库在首次设置配捐规则时开设
+    bucky_bank.match_ratio_bps = 0;
+    bucky_bank.match_period_cap = 0;
+    bucky_bank.match_period_ms = 0;
+    bucky_bank.match_period_start_ms = current_time_ms;
+    bucky_bank.match_period_matched = 0;
+    bucky_bank.match_vault_balance = 0;
+    bucky_bank.match_vault_initialized = false;
+
+    // 默认不计息，利息金库在首次设置年利率时开设
+    bucky_bank.interest_rate_bps = 0;
+    bucky_bank.last_interest_accrual_ms = current_time_ms;
+    bucky_bank.interest_carry = 0;
+    bucky_bank.interest_pending = 0;
+    bucky_bank.interest_vault_balance = 0;
+    bucky_bank.interest_vault_initialized = false;
+    bucky_bank.total_interest_earned = 0;
+
+    // 里程碑由家长另行设置
+    bucky_bank.milestones = Vec::new();
+
+    bucky_bank.current_streak_weeks = 0;
+    bucky_bank.longest_streak_weeks = 0;
+    bucky_bank.last_streak_week = 0;
+
+    bucky_bank.withdrawal_request_ttl_ms = DEFAULT_WITHDRAWAL_REQUEST_TTL_DAYS * DAY_MS;
+
+    bucky_bank.emergency_penalty_bps = 0;
+    bucky_bank.penalty_recipient = None;
+    bucky_bank.frozen = false;
+
+    // 占用家长的当前 nonce
+    bucky_bank.bank_nonce = ctx.accounts.user_bucky_banks.bank_nonce;
+    ctx.accounts.user_bucky_banks.bank_nonce = bucky_bank.bank_nonce
+        .checked_add(1)
         .ok_or(ErrorCode::Overflow)?;
 
-    // 更新用户存钱罐列表
-    let user_bucky_banks = &mut ctx.accounts.user_bucky_banks;
-    if user_bucky_banks.owner == Pubkey::default() {
-        user_bucky_banks.owner = sender;
-    }
-    user_bucky_banks.bucky_bank_ids.push(ctx.accounts.bucky_bank.key());
+    // 由家长为金库和奖励金库预存免租金最低余额，存款余额不含这部分
+    fund_rent_exempt_minimum(
+        &ctx.accounts.owner,
+        &ctx.accounts.vault,
+        &ctx.accounts.system_program,
+    )?;
+    fund_rent_exempt_minimum(
+        &ctx.accounts.owner,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.system_program,
+    )?;
+
+    // 预存达成目标奖励，按实际到账数量记录
+    bucky_bank.reward_amount = if reward_amount > 0 {
+        deposit_into_vault(
+            mint,
+            &ctx.accounts.owner,
+            &ctx.accounts.reward_vault,
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.owner_token_account.as_deref(),
+            ctx.accounts.reward_vault_token_account.as_deref_mut(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+            reward_amount,
+        )?
+    } else {
+        0
+    };
+    let reward_amount = bucky_bank.reward_amount;
+
+    // 发出全局统计增量
+    let mut stats = GlobalStatsDelta::default();
+    stats.record_bank_created(BuckyBankStatus::Active);
+    emit_global_stats(stats, bucky_bank.key(), current_time_ms);
+
+    // 写入家长索引和孩子索引
+    let bucky_bank_id = ctx.accounts.bucky_bank.key();
+    let user_bucky_banks_key = ctx.accounts.user_bucky_banks.key();
+    ctx.accounts.bucky_bank.parent_index_page = ctx.accounts.user_bucky_banks.append(
+        user_bucky_banks_key,
+        sender,
+        &mut ctx.accounts.user_bucky_banks_page,
+        bucky_bank_id,
+    );
+    let child_bucky_banks_key = ctx.accounts.child_bucky_banks.key();
+    ctx.accounts.bucky_bank.child_index_page = ctx.accounts.child_bucky_banks.append(
+        child_bucky_banks_key,
+        child_address,
+        &mut ctx.accounts.child_bucky_banks_page,
+        bucky_bank_id,
+    );
 
     // 发出事件
     emit!(BuckyBankCreated {
-        bucky_bank_id: ctx.accounts.bucky_bank.key(),
+        bucky_bank_id,
         name: config.name,
         parent: sender,
         child: child_address,
@@ -98,6 +331,10 @@ pub fn _create_bucky_bank(
         deadline_ms,
         duration_days,
         current_balance: 0,
+        mint,
+        decimals,
+        reward_amount,
+        bank_nonce: ctx.accounts.bucky_bank.bank_nonce,
     });
 
     Ok(())
@@ -2,17 +2,18 @@ use crate::{
     constants::*,
     error_code::BuckyBankError as ErrorCode,
     events::*,
-    state::{BuckyBankInfo, BankGlobalStatsInfo, BuckyBankStatus},
+    state::{BuckyBankInfo, BankGlobalStatsInfo, BuckyBankStatus, ChildAchievementsInfo, GlobalStatsDelta},
+    utils::{credit_bank_vault, deposit_into_vault, emit_global_stats},
 };
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 #[derive(Accounts)]
 pub struct Deposit<'info> {
     #[account(
-        mut,
         seeds = [BANK_GLOBAL_STATS_SEED],
         bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
     )]
     pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
 
@@ -22,13 +23,71 @@ pub struct Deposit<'info> {
     #[account(mut)]
     pub depositor: Signer<'info>,
 
+    /// 金库 PDA：接收原生 SOL 存款，同时是金库 Token 账户的 authority
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, bucky_bank.key().as_ref()],
+        bump = bucky_bank.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// 以下账户仅 SPL Token 存钱罐需要
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor,
+        token::token_program = token_program,
+    )]
+    pub depositor_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 配捐金库 PDA：孩子存款时从此配捐
+    #[account(
+        mut,
+        seeds = [MATCH_VAULT_SEED, bucky_bank.key().as_ref()],
+        bump,
+    )]
+    pub match_vault: SystemAccount<'info>,
+
+    /// 配捐金库 Token 账户，SPL Token 存钱罐开设配捐后需要
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = match_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub match_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 孩子的成就账户，首次存款时由存款人创建
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = ANCHOR_DISCRIMINATOR_SIZE + ChildAchievementsInfo::INIT_SPACE,
+        seeds = [CHILD_ACHIEVEMENTS_SEED, bucky_bank.config.child_address.as_ref()],
+        bump,
+    )]
+    pub child_achievements: Box<Account<'info, ChildAchievementsInfo>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
     pub system_program: Program<'info, System>,
 }
 
-/// 存款功能 - 只有孩子可以存款
+/// 存款功能 - 按存款人策略校验存款人，可附带留言；孩子存款时按配捐规则自动配捐，
+/// 余额越过里程碑时记录达成时间，并更新孩子的连续存款周数与成就徽章
 pub fn _deposit(
     ctx: Context<Deposit>,
-    deposit_balance: u64, // lamports
+    deposit_balance: u64, // lamports 或 Token 最小单位
+    message: Option<String>,
 ) -> Result<()> {
     let sender = ctx.accounts.depositor.key();
     let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
@@ -40,34 +99,78 @@ pub fn _deposit(
         bucky_bank.status == BuckyBankStatus::Active,
         ErrorCode::BankNotActive
     );
+    require!(!bucky_bank.frozen, ErrorCode::BankFrozen);
     require!(
-        sender == bucky_bank.config.child_address,
-        ErrorCode::NotChild
+        bucky_bank.can_deposit(&sender),
+        ErrorCode::DepositorNotAllowed
+    );
+    if let Some(message) = &message {
+        require!(
+            message.len() <= MAX_GIFT_MESSAGE_LENGTH,
+            ErrorCode::GiftMessageTooLong
+        );
+    }
+    // 截止时间后不再接受存款，需等待结算
+    require!(
+        current_time_ms <= bucky_bank.config.deadline_ms,
+        ErrorCode::DeadlinePassed
     );
 
+    // 存款金额需在存钱罐的存款限额内
     require!(
-        deposit_balance >= 10_000_000,
-        ErrorCode::InvalidDepositAmount
-    ); // 存款金额要 >=0.01 SOL
-
-    let transfer_instruction = system_instruction::transfer(
-        &ctx.accounts.depositor.key(), // 转出账户
-        &bucky_bank.key(), // 转入账户
-        deposit_balance, // 转账金额
+        deposit_balance >= bucky_bank.config.min_deposit,
+        ErrorCode::DepositTooSmall
     );
+    require!(
+        bucky_bank.config.max_deposit == 0 || deposit_balance <= bucky_bank.config.max_deposit,
+        ErrorCode::DepositTooLarge
+    );
+
+    // 余额变化前先按原余额结算利息
+    bucky_bank.checkpoint_interest(current_time_ms);
 
-    anchor_lang::solana_program::program::invoke(
-        &transfer_instruction,
-        &[
-            ctx.accounts.depositor.to_account_info(), // to_account_info 用来获取账户信息
-            bucky_bank.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
+    let received = deposit_into_vault(
+        bucky_bank.config.mint,
+        &ctx.accounts.depositor,
+        &ctx.accounts.vault,
+        ctx.accounts.mint.as_deref(),
+        ctx.accounts.depositor_token_account.as_deref(),
+        ctx.accounts.vault_token_account.as_deref_mut(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+        deposit_balance,
     )?;
 
-    // 更新存钱罐余额
+    // 孩子存款时按配捐规则从配捐金库配捐，配捐金库不足时仍完成存款
+    let by_child = sender == bucky_bank.config.child_address;
+    let (match_amount, credited) = if by_child {
+        bucky_bank.take_match(received, current_time_ms)
+    } else {
+        (0, 0)
+    };
+    let matched = if credited > 0 {
+        credit_bank_vault(
+            MATCH_VAULT_SEED,
+            &bucky_bank.key(),
+            ctx.bumps.match_vault,
+            bucky_bank.config.mint,
+            &ctx.accounts.match_vault,
+            &ctx.accounts.vault,
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.match_vault_token_account.as_deref(),
+            ctx.accounts.vault_token_account.as_deref_mut(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+            credited,
+        )?
+    } else {
+        0
+    };
+
+    // 更新存钱罐余额（按实际到账数量记账）
     bucky_bank.current_balance = bucky_bank.current_balance
-        .checked_add(deposit_balance)
+        .checked_add(received)
+        .and_then(|balance| balance.checked_add(matched))
         .ok_or(ErrorCode::Overflow)?;
 
     // 更新统计
@@ -75,19 +178,88 @@ pub fn _deposit(
         .checked_add(1)
         .ok_or(ErrorCode::Overflow)?;
     bucky_bank.last_deposit_ms = current_time_ms;
+    if by_child {
+        bucky_bank.record_streak(current_time_ms);
+    }
+
+    // 记录本次存款越过的里程碑
+    let reached_milestones = bucky_bank.reach_milestones(current_time_ms);
 
     // 检查是否达到目标
     if bucky_bank.current_balance >= bucky_bank.config.target_amount {
         bucky_bank.status = BuckyBankStatus::Completed;
     }
 
+    // 解锁孩子的成就徽章
+    let unlocked_badges = ctx
+        .accounts
+        .child_achievements
+        .record_deposit(bucky_bank, by_child);
+
+    // 发出全局统计增量
+    let mut stats = GlobalStatsDelta::default();
+    stats.record_deposit(received, bucky_bank.config.is_native());
+    stats.record_parent_credit(matched, bucky_bank.config.is_native());
+    stats.record_status_change(BuckyBankStatus::Active, bucky_bank.status);
+    emit_global_stats(stats, bucky_bank.key(), current_time_ms);
+
     // 发送事件
     emit!(DepositMade {
         bucky_bank_id: ctx.accounts.bucky_bank.key(),
-        amount: deposit_balance,
+        amount: received,
         depositor: sender,
         created_at_ms: current_time_ms,
+        mint: ctx.accounts.bucky_bank.config.mint,
+        decimals: ctx.accounts.bucky_bank.config.decimals,
+        message,
     });
 
+    let bucky_bank = &ctx.accounts.bucky_bank;
+    for index in reached_milestones {
+        let milestone = &bucky_bank.milestones[index as usize];
+        emit!(MilestoneReached {
+            bucky_bank_id: bucky_bank.key(),
+            milestone_index: index,
+            amount: milestone.amount,
+            label: milestone.label.clone(),
+            current_balance: bucky_bank.current_balance,
+            target_amount: bucky_bank.config.target_amount,
+            reached_by: sender,
+            created_at_ms: current_time_ms,
+            mint: bucky_bank.config.mint,
+            decimals: bucky_bank.config.decimals,
+        });
+    }
+
+    let child_achievements = &ctx.accounts.child_achievements;
+    for badge in unlocked_badges {
+        emit!(AchievementUnlocked {
+            child: child_achievements.child,
+            bucky_bank_id: bucky_bank.key(),
+            badge,
+            current_streak_weeks: bucky_bank.current_streak_weeks,
+            longest_streak_weeks: child_achievements.longest_streak_weeks,
+            current_balance: bucky_bank.current_balance,
+            target_amount: bucky_bank.config.target_amount,
+            created_at_ms: current_time_ms,
+        });
+    }
+
+    if match_amount > 0 {
+        emit!(MatchCredited {
+            bucky_bank_id: bucky_bank.key(),
+            parent: bucky_bank.parent,
+            depositor: sender,
+            deposit_amount: received,
+            match_amount,
+            credited_amount: matched,
+            skipped: credited < match_amount,
+            match_vault_balance: bucky_bank.match_vault_balance,
+            created_at_ms: current_time_ms,
+            mint: bucky_bank.config.mint,
+            decimals: bucky_bank.config.decimals,
+        });
+    }
+
     Ok(())
 }
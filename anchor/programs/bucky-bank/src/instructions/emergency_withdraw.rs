@@ -0,0 +1,128 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, GlobalStatsDelta},
+    utils::{emit_global_stats, payout_from_vault},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(
+        mut,
+        has_one = parent @ ErrorCode::NotParent,
+    )]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub parent: Signer<'info>,
+
+    /// 家长指定的收款地址
+    /// CHECK: 任意地址，只接收转账
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// 金库 PDA：原生 SOL 从此转出，SPL Token 转出时作为签名者
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, bucky_bank.key().as_ref()],
+        bump = bucky_bank.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// 以下账户仅 SPL Token 存钱罐需要
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 收款地址的 Token 账户
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// 家长紧急取款：不经取款请求，直接把存款转到家长指定的地址
+///
+/// 冻结期间同样可以执行。已批准但未提取的请求在余额不足时将无法提取。
+pub fn _emergency_withdraw(
+    ctx: Context<EmergencyWithdraw>,
+    amount: u64,
+    reason: String,
+) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+    let destination = ctx.accounts.destination.key();
+
+    require!(
+        amount > 0 && amount <= bucky_bank.current_balance,
+        ErrorCode::InvalidWithdrawalAmount
+    );
+    require!(reason.len() <= MAX_REASON_LENGTH, ErrorCode::ReasonTooLong);
+    if let Some(to) = ctx.accounts.destination_token_account.as_deref() {
+        require_keys_eq!(to.owner, destination, ErrorCode::InvalidRecipient);
+    }
+
+    // 余额变化前先按原余额结算利息
+    bucky_bank.checkpoint_interest(current_time_ms);
+
+    bucky_bank.current_balance = bucky_bank.current_balance
+        .checked_sub(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    payout_from_vault(
+        VAULT_SEED,
+        &bucky_bank.key(),
+        bucky_bank.vault_bump,
+        bucky_bank.config.mint,
+        &ctx.accounts.vault,
+        &ctx.accounts.destination.to_account_info(),
+        ctx.accounts.mint.as_deref(),
+        ctx.accounts.vault_token_account.as_deref(),
+        ctx.accounts.destination_token_account.as_deref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.system_program,
+        amount,
+    )?;
+
+    // 发出全局统计增量
+    let mut stats = GlobalStatsDelta::default();
+    stats.record_withdrawal(amount, bucky_bank.config.is_native());
+    emit_global_stats(stats, bucky_bank.key(), current_time_ms);
+
+    emit!(EmergencyWithdrawal {
+        bucky_bank_id: bucky_bank.key(),
+        parent: bucky_bank.parent,
+        destination,
+        amount,
+        left_balance: bucky_bank.current_balance,
+        reason,
+        frozen: bucky_bank.frozen,
+        created_at_ms: current_time_ms,
+        mint: bucky_bank.config.mint,
+        decimals: bucky_bank.config.decimals,
+    });
+
+    Ok(())
+}
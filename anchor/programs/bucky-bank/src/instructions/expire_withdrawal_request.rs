@@ -0,0 +1,67 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, WithdrawalRequestInfo, WithdrawalStatus},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExpireWithdrawalRequest<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    /// 过期后关闭请求账户，租金退还给请求者
+    #[account(mut, close = requester)]
+    pub withdrawal_request: Account<'info, WithdrawalRequestInfo>,
+
+    #[account(
+        mut,
+        address = withdrawal_request.requester @ ErrorCode::NotChildForWithdrawal,
+    )]
+    pub requester: SystemAccount<'info>,
+
+    pub signer: Signer<'info>,
+}
+
+/// 关闭已过期的取款请求
+///
+/// 任何人都可以调用。超过有效期仍待审批的请求标记为已过期，请求账户关闭并将租金退还请求者。
+pub fn _expire_withdrawal_request(ctx: Context<ExpireWithdrawalRequest>) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+    let withdrawal_request = &mut ctx.accounts.withdrawal_request;
+
+    require!(
+        withdrawal_request.bucky_bank_id == bucky_bank.key(),
+        ErrorCode::RequestNotFound
+    );
+    require!(
+        current_time_ms >= withdrawal_request.expires_at_ms,
+        ErrorCode::RequestNotExpired
+    );
+
+    // 只有待审批的请求会过期，自动批准的请求创建时即为已批准，不占用需退回的额度
+    withdrawal_request.transition_to(WithdrawalStatus::Expired)?;
+    bucky_bank.open_request_count = bucky_bank.open_request_count.saturating_sub(1);
+
+    emit!(WithdrawalRequestExpired {
+        request_id: withdrawal_request.key(),
+        bucky_bank_id: bucky_bank.key(),
+        amount: withdrawal_request.requested_amount,
+        requester: withdrawal_request.requester,
+        expires_at_ms: withdrawal_request.expires_at_ms,
+        expired_by: ctx.accounts.signer.key(),
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
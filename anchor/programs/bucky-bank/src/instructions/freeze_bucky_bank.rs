@@ -0,0 +1,91 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct FreezeBuckyBank<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(
+        mut,
+        has_one = parent @ ErrorCode::NotParent,
+    )]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub parent: Signer<'info>,
+}
+
+/// 家长冻结存钱罐（如孩子钱包被盗）
+///
+/// 冻结期间不能存款、申请取款或提取，家长仍可通过紧急取款转出资金。
+pub fn _freeze_bucky_bank(ctx: Context<FreezeBuckyBank>, reason: String) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(!bucky_bank.frozen, ErrorCode::BankFrozen);
+    require!(reason.len() <= MAX_REASON_LENGTH, ErrorCode::ReasonTooLong);
+
+    // 冻结期间不计息，冻结前先结算利息
+    bucky_bank.checkpoint_interest(current_time_ms);
+    bucky_bank.frozen = true;
+
+    emit!(BuckyBankFrozen {
+        bucky_bank_id: bucky_bank.key(),
+        parent: bucky_bank.parent,
+        reason,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnfreezeBuckyBank<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(
+        mut,
+        has_one = parent @ ErrorCode::NotParent,
+    )]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub parent: Signer<'info>,
+}
+
+/// 家长解除存钱罐冻结
+pub fn _unfreeze_bucky_bank(ctx: Context<UnfreezeBuckyBank>, reason: String) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(bucky_bank.frozen, ErrorCode::BankNotFrozen);
+    require!(reason.len() <= MAX_REASON_LENGTH, ErrorCode::ReasonTooLong);
+
+    // 跳过冻结期间，解冻后重新开始计息
+    bucky_bank.checkpoint_interest(current_time_ms);
+    bucky_bank.frozen = false;
+
+    emit!(BuckyBankUnfrozen {
+        bucky_bank_id: bucky_bank.key(),
+        parent: bucky_bank.parent,
+        reason,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use crate::{
-    constants::{ANCHOR_DISCRIMINATOR_SIZE, BANK_GLOBAL_STATS_SEED},
+    constants::{ANCHOR_DISCRIMINATOR_SIZE, BANK_GLOBAL_STATS_SEED, DEFAULT_MIN_NATIVE_DEPOSIT},
+    error_code::BuckyBankError as ErrorCode,
+    program::BuckyBank,
     state::BankGlobalStatsInfo,
 };
 use anchor_lang::prelude::*;
@@ -18,6 +20,19 @@ pub struct InitializeBankGlobalStats<'info> {
     )]
     pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
 
+    /// 只有程序升级权限地址可以初始化，防止被抢先初始化
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ ErrorCode::NotUpgradeAuthority,
+    )]
+    pub program: Program<'info, BuckyBank>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(owner.key())
+            @ ErrorCode::NotUpgradeAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -27,6 +42,10 @@ pub fn _initialize_bank_global_stats(ctx: Context<InitializeBankGlobalStats>) ->
     bank_global_stats.total_bucky_banks = 0;
     bank_global_stats.total_deposits = 0;
     bank_global_stats.total_withdrawals = 0;
+    bank_global_stats.paused = false;
+    bank_global_stats.pending_admin = None;
+    bank_global_stats.default_min_deposit = DEFAULT_MIN_NATIVE_DEPOSIT;
+    bank_global_stats.default_max_deposit = 0;
 
     Ok(())
 }
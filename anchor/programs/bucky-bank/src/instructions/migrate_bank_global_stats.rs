@@ -0,0 +1,89 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::BankGlobalStatsInfo,
+};
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+    Discriminator,
+};
+
+#[derive(Accounts)]
+pub struct MigrateBankGlobalStats<'info> {
+    /// CHECK: 旧布局的账户无法按当前结构反序列化，在指令中校验判别符和管理员
+    #[account(
+        mut,
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        owner = crate::ID,
+    )]
+    pub bank_global_stats: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// 管理员将全局配置账户扩容到当前布局
+///
+/// 新追加的字段按零值读取（未暂停、无待接受管理员、单笔存款不限额），默认最低存款额设为
+/// `DEFAULT_MIN_NATIVE_DEPOSIT`，扩容所需的租金由管理员补足。
+pub fn _migrate_bank_global_stats(ctx: Context<MigrateBankGlobalStats>) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bank_global_stats = ctx.accounts.bank_global_stats.to_account_info();
+    let admin = ctx.accounts.admin.key();
+    let old_len = bank_global_stats.data_len();
+    let new_len = ANCHOR_DISCRIMINATOR_SIZE + BankGlobalStatsInfo::INIT_SPACE;
+    require!(old_len < new_len, ErrorCode::AlreadyMigrated);
+
+    // 旧布局中管理员字段的位置与当前布局一致
+    {
+        let data = bank_global_stats.try_borrow_data()?;
+        require!(
+            data.len() >= BankGlobalStatsInfo::LEGACY_ADMIN_OFFSET + 32
+                && data[..ANCHOR_DISCRIMINATOR_SIZE] == *BankGlobalStatsInfo::DISCRIMINATOR,
+            ErrorCode::InvalidGlobalStatsAccount
+        );
+        let offset = BankGlobalStatsInfo::LEGACY_ADMIN_OFFSET;
+        let stored_admin = Pubkey::try_from(&data[offset..offset + 32])
+            .map_err(|_| error!(ErrorCode::InvalidGlobalStatsAccount))?;
+        require_keys_eq!(stored_admin, admin, ErrorCode::NotAdmin);
+    }
+
+    // 补足扩容后的租金
+    let rent_shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(bank_global_stats.lamports());
+    if rent_shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: bank_global_stats.clone(),
+                },
+            ),
+            rent_shortfall,
+        )?;
+    }
+    bank_global_stats.realloc(new_len, true)?;
+
+    let mut stats = BankGlobalStatsInfo::try_deserialize(&mut &bank_global_stats.try_borrow_data()?[..])?;
+    if stats.default_min_deposit == 0 {
+        stats.default_min_deposit = DEFAULT_MIN_NATIVE_DEPOSIT;
+    }
+    stats.try_serialize(&mut &mut bank_global_stats.try_borrow_mut_data()?[..])?;
+
+    emit!(BankGlobalStatsMigrated {
+        admin,
+        old_len: old_len as u64,
+        new_len: new_len as u64,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
@@ -14,4 +14,91 @@ pub mod approve_withdrawal;
 pub use approve_withdrawal::*;
 
 pub mod withdraw;
-pub use withdraw::*;
\ No newline at end of file
+pub use withdraw::*;
+
+pub mod sync_balance;
+pub use sync_balance::*;
+
+pub mod set_saving_reward;
+pub use set_saving_reward::*;
+
+pub mod claim_saving_rewards;
+pub use claim_saving_rewards::*;
+
+pub mod settle_bucky_bank;
+pub use settle_bucky_bank::*;
+
+pub mod cancel_withdrawal;
+pub use cancel_withdrawal::*;
+
+pub mod close_bucky_bank;
+pub use close_bucky_bank::*;
+
+pub mod set_depositor_policy;
+pub use set_depositor_policy::*;
+
+pub mod set_guardians;
+pub use set_guardians::*;
+
+pub mod set_auto_approval;
+pub use set_auto_approval::*;
+
+pub mod update_bucky_bank_config;
+pub use update_bucky_bank_config::*;
+
+pub mod transfer_parent;
+pub use transfer_parent::*;
+
+pub mod rotate_child;
+pub use rotate_child::*;
+
+pub mod set_paused;
+pub use set_paused::*;
+
+pub mod transfer_admin;
+pub use transfer_admin::*;
+
+pub mod set_matching_rule;
+pub use set_matching_rule::*;
+
+pub mod set_interest_rate;
+pub use set_interest_rate::*;
+
+pub mod accrue_interest;
+pub use accrue_interest::*;
+
+pub mod set_milestones;
+pub use set_milestones::*;
+
+pub mod expire_withdrawal_request;
+pub use expire_withdrawal_request::*;
+
+pub mod set_withdrawal_request_ttl;
+pub use set_withdrawal_request_ttl::*;
+
+pub mod set_default_deposit_limits;
+pub use set_default_deposit_limits::*;
+
+pub mod set_deposit_limits;
+pub use set_deposit_limits::*;
+
+pub mod set_lock_mode;
+pub use set_lock_mode::*;
+
+pub mod freeze_bucky_bank;
+pub use freeze_bucky_bank::*;
+
+pub mod emergency_withdraw;
+pub use emergency_withdraw::*;
+
+pub mod close_withdrawal_request;
+pub use close_withdrawal_request::*;
+
+pub mod migrate_bank_global_stats;
+pub use migrate_bank_global_stats::*;
+
+pub mod close_legacy_bucky_bank;
+pub use close_legacy_bucky_bank::*;
+
+pub mod close_legacy_withdrawal_request;
+pub use close_legacy_withdrawal_request::*;
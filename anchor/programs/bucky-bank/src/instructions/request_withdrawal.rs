@@ -2,12 +2,19 @@ use crate::{
     constants::*,
     error_code::BuckyBankError as ErrorCode,
     events::*,
-    state::{BuckyBankInfo, WithdrawalRequestInfo, WithdrawalStatus},
+    state::{BankGlobalStatsInfo, BuckyBankInfo, WithdrawalRequestInfo, WithdrawalStatus},
 };
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct RequestWithdrawal<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
     #[account(mut)]
     pub bucky_bank: Account<'info, BuckyBankInfo>,
 
@@ -31,11 +38,15 @@ pub struct RequestWithdrawal<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// 取款请求功能 - 只有孩子可以请求取款
+/// 取款请求功能 - 只有孩子可以请求取款，请求按存钱罐设置的有效期过期
+///
+/// 锁定模式下截止时间前只接受紧急请求（`emergency` 为 true），紧急请求需要全部监护人批准，
+/// 不适用自动批准，提取时按存钱罐设置扣除罚金。
 pub fn _request_withdrawal(
     ctx: Context<RequestWithdrawal>,
     amount: u64,
     reason: String,
+    emergency: bool,
 ) -> Result<()> {
     let sender = ctx.accounts.requester.key();
     let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
@@ -47,6 +58,7 @@ pub fn _request_withdrawal(
         sender == bucky_bank.config.child_address,
         ErrorCode::NotChildForWithdrawal
     );
+    require!(!bucky_bank.frozen, ErrorCode::BankFrozen);
 
     require!(
         amount > 0 && amount <= bucky_bank.current_balance,
@@ -58,31 +70,82 @@ pub fn _request_withdrawal(
         ErrorCode::ReasonTooLong
     );
 
+    // 锁定期内只接受紧急请求；锁定期外的请求一律按普通请求处理
+    let time_locked = bucky_bank.is_time_locked(current_time_ms);
+    require!(!time_locked || emergency, ErrorCode::BankTimeLocked);
+    let emergency = emergency && time_locked;
+
     // 创建取款请求
     let withdrawal_request = &mut ctx.accounts.withdrawal_request;
     withdrawal_request.bucky_bank_id = bucky_bank.key();
     withdrawal_request.requester = sender;
-    withdrawal_request.amount = amount;
+    withdrawal_request.requested_amount = amount;
+    withdrawal_request.approved_amount = 0;
     withdrawal_request.reason = reason.clone();
     withdrawal_request.status = WithdrawalStatus::Pending;
-    withdrawal_request.approved_by = bucky_bank.parent;
+    withdrawal_request.approved_by = Pubkey::default();
     withdrawal_request.created_at_ms = current_time_ms;
     withdrawal_request.approved_at_ms = 0;
+    withdrawal_request.completed_at_ms = 0;
+    withdrawal_request.approvals = Vec::new();
+    withdrawal_request.rejections = Vec::new();
+    withdrawal_request.expires_at_ms = current_time_ms.saturating_add(bucky_bank.withdrawal_request_ttl_ms);
+    withdrawal_request.emergency = emergency;
+    if emergency {
+        withdrawal_request.penalty_bps = bucky_bank.emergency_penalty_bps;
+        withdrawal_request.penalty_recipient = bucky_bank.penalty_recipient.unwrap_or(bucky_bank.parent);
+    } else {
+        withdrawal_request.penalty_bps = 0;
+        withdrawal_request.penalty_recipient = Pubkey::default();
+    }
+
+    // 符合自动批准规则时直接批准，紧急请求必须由监护人审批
+    let auto_approved = !emergency && bucky_bank.try_auto_approve(amount, current_time_ms);
+    if auto_approved {
+        withdrawal_request.transition_to(WithdrawalStatus::Approved)?;
+        withdrawal_request.approved_amount = amount;
+        withdrawal_request.approved_at_ms = current_time_ms;
+    }
+    withdrawal_request.auto_approved = auto_approved;
+    let status = withdrawal_request.status;
+    let expires_at_ms = withdrawal_request.expires_at_ms;
 
     // 增加取款请求计数器
     bucky_bank.withdrawal_request_counter += 1;
+    bucky_bank.open_request_count = bucky_bank.open_request_count
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
 
     // 发送事件
+    let request_id = ctx.accounts.withdrawal_request.key();
     emit!(EventWithdrawalRequested {
-        request_id: ctx.accounts.withdrawal_request.key(),
+        request_id,
         bucky_bank_id: bucky_bank.key(),
         amount,
         requester: sender,
         reason,
-        status: WithdrawalStatus::Pending as u8,
-        approved_by: bucky_bank.parent,
+        status: status as u8,
+        approved_by: Pubkey::default(),
         created_at_ms: current_time_ms,
+        mint: bucky_bank.config.mint,
+        decimals: bucky_bank.config.decimals,
+        auto_approved,
+        expires_at_ms,
+        emergency,
     });
 
+    if auto_approved {
+        emit!(WithdrawalAutoApproved {
+            request_id,
+            bucky_bank_id: bucky_bank.key(),
+            amount,
+            requester: sender,
+            auto_approve_limit: bucky_bank.auto_approve_limit,
+            daily_spent: bucky_bank.daily_spent,
+            weekly_spent: bucky_bank.weekly_spent,
+            created_at_ms: current_time_ms,
+        });
+    }
+
     Ok(())
 }
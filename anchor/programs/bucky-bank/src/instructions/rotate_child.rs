@@ -0,0 +1,153 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{
+        BankGlobalStatsInfo, BuckyBankInfo, UserBuckyBanksInfo, UserBuckyBanksPage,
+        WithdrawalRequestInfo, WithdrawalStatus,
+    },
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(new_child: Pubkey)]
+pub struct RotateChild<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    #[account(
+        mut,
+        seeds = [CHILD_BUCKY_BANKS_SEED, bucky_bank.config.child_address.as_ref()],
+        bump,
+    )]
+    pub old_child_bucky_banks: Box<Account<'info, UserBuckyBanksInfo>>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKY_BANK_INDEX_PAGE_SEED,
+            old_child_bucky_banks.key().as_ref(),
+            bucky_bank.child_index_page.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub old_child_bucky_banks_page: Box<Account<'info, UserBuckyBanksPage>>,
+
+    #[account(
+        init_if_needed,
+        payer = parent,
+        space = ANCHOR_DISCRIMINATOR_SIZE + UserBuckyBanksInfo::INIT_SPACE,
+        seeds = [CHILD_BUCKY_BANKS_SEED, new_child.as_ref()],
+        bump,
+    )]
+    pub new_child_bucky_banks: Box<Account<'info, UserBuckyBanksInfo>>,
+
+    #[account(
+        init_if_needed,
+        payer = parent,
+        space = ANCHOR_DISCRIMINATOR_SIZE + UserBuckyBanksPage::INIT_SPACE,
+        seeds = [
+            BUCKY_BANK_INDEX_PAGE_SEED,
+            new_child_bucky_banks.key().as_ref(),
+            new_child_bucky_banks.current_page.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub new_child_bucky_banks_page: Box<Account<'info, UserBuckyBanksPage>>,
+
+    #[account(mut)]
+    pub parent: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// 家长更换孩子地址
+///
+/// 用于孩子钱包丢失或泄露的情况。原孩子的全部未结束取款请求须通过 remaining_accounts 传入，
+/// 随更换一并取消并关闭：原钥匙已丢失或泄露，请求租金退还家长。
+/// 存钱罐随之从原孩子的索引移到新孩子的索引。
+pub fn _rotate_child<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RotateChild<'info>>,
+    new_child: Pubkey,
+) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+    require!(
+        ctx.remaining_accounts.len() as u64 == bucky_bank.open_request_count,
+        ErrorCode::RequestsStillOpen
+    );
+
+    let old_child = bucky_bank.config.child_address;
+    require!(
+        new_child != Pubkey::default() && new_child != old_child,
+        ErrorCode::InvalidNewAddress
+    );
+
+    // 取消并关闭原孩子的未结束请求
+    let bucky_bank_id = bucky_bank.key();
+    let parent = ctx.accounts.parent.to_account_info();
+    for account_info in ctx.remaining_accounts.iter() {
+        let mut withdrawal_request = Account::<WithdrawalRequestInfo>::try_from(account_info)?;
+        require!(
+            withdrawal_request.bucky_bank_id == bucky_bank_id,
+            ErrorCode::RequestNotFound
+        );
+
+        let previous_status = withdrawal_request.status;
+        withdrawal_request.transition_to(WithdrawalStatus::Cancelled)?;
+        if previous_status == WithdrawalStatus::Approved && withdrawal_request.auto_approved {
+            bucky_bank.release_auto_approval(
+                withdrawal_request.approved_amount,
+                withdrawal_request.approved_at_ms,
+            );
+        }
+
+        emit!(EventWithdrawalCancelled {
+            request_id: withdrawal_request.key(),
+            bucky_bank_id,
+            amount: withdrawal_request.requested_amount,
+            requester: withdrawal_request.requester,
+            previous_status: previous_status as u8,
+            created_at_ms: current_time_ms,
+        });
+
+        withdrawal_request.close(parent.clone())?;
+    }
+    bucky_bank.open_request_count = 0;
+
+    bucky_bank.config.child_address = new_child;
+
+    // 从原孩子索引移到新孩子索引
+    ctx.accounts
+        .old_child_bucky_banks
+        .remove(&mut ctx.accounts.old_child_bucky_banks_page, bucky_bank_id);
+
+    let new_child_bucky_banks_key = ctx.accounts.new_child_bucky_banks.key();
+    ctx.accounts.bucky_bank.child_index_page = ctx.accounts.new_child_bucky_banks.append(
+        new_child_bucky_banks_key,
+        new_child,
+        &mut ctx.accounts.new_child_bucky_banks_page,
+        bucky_bank_id,
+    );
+
+    emit!(ChildRotated {
+        bucky_bank_id,
+        parent: sender,
+        old_child,
+        new_child,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
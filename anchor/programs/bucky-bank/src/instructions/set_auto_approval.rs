@@ -0,0 +1,55 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetAutoApproval<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub parent: Signer<'info>,
+}
+
+/// 家长设置自动批准规则
+///
+/// `auto_approve_limit` 为 0 时关闭自动批准；`daily_cap`、`weekly_cap` 为 0 时不限。
+/// 已用额度保留到各自窗口结束。
+pub fn _set_auto_approval(
+    ctx: Context<SetAutoApproval>,
+    auto_approve_limit: u64,
+    daily_cap: u64,
+    weekly_cap: u64,
+) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+
+    bucky_bank.auto_approve_limit = auto_approve_limit;
+    bucky_bank.daily_cap = daily_cap;
+    bucky_bank.weekly_cap = weekly_cap;
+
+    emit!(AutoApprovalRulesUpdated {
+        bucky_bank_id: bucky_bank.key(),
+        parent: sender,
+        auto_approve_limit,
+        daily_cap,
+        weekly_cap,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
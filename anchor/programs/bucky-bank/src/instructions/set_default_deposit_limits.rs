@@ -0,0 +1,50 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, Config},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetDefaultDepositLimits<'info> {
+    #[account(
+        mut,
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        has_one = admin @ ErrorCode::NotAdmin,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    pub admin: Signer<'info>,
+}
+
+/// 管理员设置新建存钱罐的默认存款限额
+///
+/// 限额以 lamports 计，SPL Token 存钱罐创建时按 Mint 精度换算为相同的整币数额。
+/// 只影响之后创建的存钱罐，已有存钱罐保留各自的限额。
+pub fn _set_default_deposit_limits(
+    ctx: Context<SetDefaultDepositLimits>,
+    default_min_deposit: u64,
+    default_max_deposit: u64,
+) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    require!(
+        Config::valid_deposit_limits(default_min_deposit, default_max_deposit),
+        ErrorCode::InvalidDepositLimits
+    );
+
+    let bank_global_stats = &mut ctx.accounts.bank_global_stats;
+    bank_global_stats.default_min_deposit = default_min_deposit;
+    bank_global_stats.default_max_deposit = default_max_deposit;
+
+    emit!(DefaultDepositLimitsUpdated {
+        admin: ctx.accounts.admin.key(),
+        default_min_deposit,
+        default_max_deposit,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, BuckyBankStatus, Config},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetDepositLimits<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub parent: Signer<'info>,
+}
+
+/// 家长设置存钱罐的单笔存款限额
+///
+/// `max_deposit` 为 0 表示不限。
+pub fn _set_deposit_limits(
+    ctx: Context<SetDepositLimits>,
+    min_deposit: u64,
+    max_deposit: u64,
+) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+    require!(
+        bucky_bank.status == BuckyBankStatus::Active,
+        ErrorCode::BankNotActive
+    );
+    require!(
+        Config::valid_deposit_limits(min_deposit, max_deposit),
+        ErrorCode::InvalidDepositLimits
+    );
+
+    bucky_bank.config.min_deposit = min_deposit;
+    bucky_bank.config.max_deposit = max_deposit;
+
+    emit!(DepositLimitsUpdated {
+        bucky_bank_id: bucky_bank.key(),
+        parent: sender,
+        min_deposit,
+        max_deposit,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
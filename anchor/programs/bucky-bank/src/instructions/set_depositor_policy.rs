@@ -0,0 +1,55 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, DepositorPolicy},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetDepositorPolicy<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub parent: Signer<'info>,
+}
+
+/// 家长设置存款人策略与白名单
+///
+/// 白名单会整体替换，仅在 `Allowlist` 策略下生效。
+pub fn _set_depositor_policy(
+    ctx: Context<SetDepositorPolicy>,
+    depositor_policy: DepositorPolicy,
+    allowed_depositors: Vec<Pubkey>,
+) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+    require!(
+        allowed_depositors.len() <= MAX_ALLOWED_DEPOSITORS,
+        ErrorCode::TooManyDepositors
+    );
+
+    bucky_bank.depositor_policy = depositor_policy;
+    bucky_bank.allowed_depositors = allowed_depositors;
+
+    emit!(DepositorPolicyUpdated {
+        bucky_bank_id: bucky_bank.key(),
+        parent: sender,
+        depositor_policy,
+        allowed_depositors: bucky_bank.allowed_depositors.clone(),
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub parent: Signer<'info>,
+}
+
+/// 家长设置监护人列表与审批门槛（M-of-N）
+///
+/// 仍有未结束的取款请求时不能修改，避免已投的票失效。
+pub fn _set_guardians(
+    ctx: Context<SetGuardians>,
+    guardians: Vec<Pubkey>,
+    approval_threshold: u8,
+) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+    require!(
+        bucky_bank.open_request_count == 0,
+        ErrorCode::RequestsStillOpen
+    );
+    require!(
+        !guardians.is_empty() && guardians.len() <= MAX_GUARDIANS,
+        ErrorCode::InvalidGuardians
+    );
+    for (i, guardian) in guardians.iter().enumerate() {
+        require!(
+            *guardian != Pubkey::default() && !guardians[..i].contains(guardian),
+            ErrorCode::InvalidGuardians
+        );
+    }
+    require!(
+        approval_threshold >= 1 && approval_threshold as usize <= guardians.len(),
+        ErrorCode::InvalidApprovalThreshold
+    );
+
+    bucky_bank.guardians = guardians;
+    bucky_bank.approval_threshold = approval_threshold;
+
+    emit!(GuardiansUpdated {
+        bucky_bank_id: bucky_bank.key(),
+        parent: sender,
+        guardians: bucky_bank.guardians.clone(),
+        approval_threshold,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, BuckyBankStatus},
+    utils::{deposit_into_vault, fund_rent_exempt_minimum, payout_from_vault},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct SetInterestRate<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    #[account(mut)]
+    pub parent: Signer<'info>,
+
+    /// 利息金库 PDA：存放家长预存的利息资金
+    #[account(
+        mut,
+        seeds = [INTEREST_VAULT_SEED, bucky_bank.key().as_ref()],
+        bump,
+    )]
+    pub interest_vault: SystemAccount<'info>,
+
+    /// 以下账户仅 SPL Token 存钱罐需要
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        init_if_needed,
+        payer = parent,
+        associated_token::mint = mint,
+        associated_token::authority = interest_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub interest_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = parent,
+        token::token_program = token_program,
+    )]
+    pub parent_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// 家长设置年利率并调整利息金库余额
+///
+/// 调整前先按原利率结算利息。利息金库按 `interest_vault_balance` 托管：
+/// 调高时由家长补足差额，调低时差额退回家长。`interest_rate_bps` 为 0 时停止计息。
+pub fn _set_interest_rate(
+    ctx: Context<SetInterestRate>,
+    interest_rate_bps: u16,
+    interest_vault_balance: u64,
+) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+    require!(
+        bucky_bank.status == BuckyBankStatus::Active,
+        ErrorCode::BankNotActive
+    );
+    require!(
+        interest_rate_bps <= MAX_INTEREST_RATE_BPS,
+        ErrorCode::InvalidInterestRate
+    );
+
+    // 首次设置时开设利息金库
+    if !bucky_bank.interest_vault_initialized {
+        if bucky_bank.config.mint.is_some() {
+            require!(
+                ctx.accounts.interest_vault_token_account.is_some(),
+                ErrorCode::MissingTokenAccounts
+            );
+        }
+        fund_rent_exempt_minimum(
+            &ctx.accounts.parent,
+            &ctx.accounts.interest_vault,
+            &ctx.accounts.system_program,
+        )?;
+        bucky_bank.interest_vault_initialized = true;
+    }
+
+    // 按原利率结算到当前时间
+    bucky_bank.checkpoint_interest(current_time_ms);
+
+    let old_interest_vault_balance = bucky_bank.interest_vault_balance;
+
+    if interest_vault_balance > old_interest_vault_balance {
+        let received = deposit_into_vault(
+            bucky_bank.config.mint,
+            &ctx.accounts.parent,
+            &ctx.accounts.interest_vault,
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.parent_token_account.as_deref(),
+            ctx.accounts.interest_vault_token_account.as_deref_mut(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+            interest_vault_balance - old_interest_vault_balance,
+        )?;
+        bucky_bank.interest_vault_balance = old_interest_vault_balance
+            .checked_add(received)
+            .ok_or(ErrorCode::Overflow)?;
+    } else if interest_vault_balance < old_interest_vault_balance {
+        payout_from_vault(
+            INTEREST_VAULT_SEED,
+            &bucky_bank.key(),
+            ctx.bumps.interest_vault,
+            bucky_bank.config.mint,
+            &ctx.accounts.interest_vault,
+            &ctx.accounts.parent.to_account_info(),
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.interest_vault_token_account.as_deref(),
+            ctx.accounts.parent_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+            old_interest_vault_balance - interest_vault_balance,
+        )?;
+        bucky_bank.interest_vault_balance = interest_vault_balance;
+    }
+
+    bucky_bank.interest_rate_bps = interest_rate_bps;
+
+    emit!(InterestRateUpdated {
+        bucky_bank_id: bucky_bank.key(),
+        parent: sender,
+        interest_rate_bps,
+        interest_vault_balance: bucky_bank.interest_vault_balance,
+        created_at_ms: current_time_ms,
+        mint: bucky_bank.config.mint,
+        decimals: bucky_bank.config.decimals,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, BuckyBankStatus, LockMode},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetLockMode<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub parent: Signer<'info>,
+}
+
+/// 家长设置锁定模式与紧急取款罚金
+///
+/// 锁定模式下截止时间前只能申请紧急取款。罚金按 `emergency_penalty_bps` 从批准金额中扣除，
+/// 付给 `penalty_recipient`（如慈善地址），为 None 时付给家长。
+/// 仍有未结束的取款请求时不能修改，避免已提交的请求规则改变。
+pub fn _set_lock_mode(
+    ctx: Context<SetLockMode>,
+    lock_mode: LockMode,
+    emergency_penalty_bps: u16,
+    penalty_recipient: Option<Pubkey>,
+) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+    require!(
+        bucky_bank.status == BuckyBankStatus::Active,
+        ErrorCode::BankNotActive
+    );
+    require!(
+        bucky_bank.open_request_count == 0,
+        ErrorCode::RequestsStillOpen
+    );
+    require!(
+        emergency_penalty_bps <= MAX_EMERGENCY_PENALTY_BPS,
+        ErrorCode::InvalidPenaltyRate
+    );
+    if let Some(penalty_recipient) = penalty_recipient {
+        require!(
+            penalty_recipient != Pubkey::default() && penalty_recipient != bucky_bank.config.child_address,
+            ErrorCode::InvalidNewAddress
+        );
+    }
+
+    bucky_bank.config.lock_mode = lock_mode;
+    bucky_bank.emergency_penalty_bps = emergency_penalty_bps;
+    bucky_bank.penalty_recipient = penalty_recipient;
+
+    emit!(LockModeUpdated {
+        bucky_bank_id: bucky_bank.key(),
+        parent: sender,
+        lock_mode,
+        emergency_penalty_bps,
+        penalty_recipient: penalty_recipient.unwrap_or(sender),
+        deadline_ms: bucky_bank.config.deadline_ms,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
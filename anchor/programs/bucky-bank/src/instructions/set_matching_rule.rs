@@ -0,0 +1,165 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, BuckyBankStatus},
+    utils::{deposit_into_vault, fund_rent_exempt_minimum, payout_from_vault},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct SetMatchingRule<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    #[account(mut)]
+    pub parent: Signer<'info>,
+
+    /// 配捐金库 PDA：存放家长预存的配捐资金
+    #[account(
+        mut,
+        seeds = [MATCH_VAULT_SEED, bucky_bank.key().as_ref()],
+        bump,
+    )]
+    pub match_vault: SystemAccount<'info>,
+
+    /// 以下账户仅 SPL Token 存钱罐需要
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        init_if_needed,
+        payer = parent,
+        associated_token::mint = mint,
+        associated_token::authority = match_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub match_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = parent,
+        token::token_program = token_program,
+    )]
+    pub parent_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// 家长设置配捐规则并调整配捐金库余额
+///
+/// 孩子每笔存款按 `match_ratio_bps` 从配捐金库配捐，每 `match_period_days` 天内
+/// 配捐总额不超过 `match_period_cap`（0 表示不限）。配捐金库按 `match_vault_balance`
+/// 托管：调高时由家长补足差额，调低时差额退回家长。`match_ratio_bps` 为 0 时关闭配捐。
+pub fn _set_matching_rule(
+    ctx: Context<SetMatchingRule>,
+    match_ratio_bps: u16,
+    match_period_cap: u64,
+    match_period_days: u64,
+    match_vault_balance: u64,
+) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+    require!(
+        bucky_bank.status == BuckyBankStatus::Active,
+        ErrorCode::BankNotActive
+    );
+    require!(
+        match_ratio_bps <= MAX_MATCH_RATIO_BPS,
+        ErrorCode::InvalidMatchRatio
+    );
+    require!(match_period_days > 0, ErrorCode::InvalidMatchPeriod);
+    let match_period_ms = match_period_days
+        .checked_mul(DAY_MS)
+        .ok_or(ErrorCode::InvalidMatchPeriod)?;
+
+    // 首次设置时开设配捐金库
+    if !bucky_bank.match_vault_initialized {
+        if bucky_bank.config.mint.is_some() {
+            require!(
+                ctx.accounts.match_vault_token_account.is_some(),
+                ErrorCode::MissingTokenAccounts
+            );
+        }
+        fund_rent_exempt_minimum(
+            &ctx.accounts.parent,
+            &ctx.accounts.match_vault,
+            &ctx.accounts.system_program,
+        )?;
+        bucky_bank.match_vault_initialized = true;
+        bucky_bank.match_period_start_ms = current_time_ms;
+        bucky_bank.match_period_matched = 0;
+    }
+
+    let old_match_vault_balance = bucky_bank.match_vault_balance;
+
+    if match_vault_balance > old_match_vault_balance {
+        let received = deposit_into_vault(
+            bucky_bank.config.mint,
+            &ctx.accounts.parent,
+            &ctx.accounts.match_vault,
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.parent_token_account.as_deref(),
+            ctx.accounts.match_vault_token_account.as_deref_mut(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+            match_vault_balance - old_match_vault_balance,
+        )?;
+        bucky_bank.match_vault_balance = old_match_vault_balance
+            .checked_add(received)
+            .ok_or(ErrorCode::Overflow)?;
+    } else if match_vault_balance < old_match_vault_balance {
+        payout_from_vault(
+            MATCH_VAULT_SEED,
+            &bucky_bank.key(),
+            ctx.bumps.match_vault,
+            bucky_bank.config.mint,
+            &ctx.accounts.match_vault,
+            &ctx.accounts.parent.to_account_info(),
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.match_vault_token_account.as_deref(),
+            ctx.accounts.parent_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+            old_match_vault_balance - match_vault_balance,
+        )?;
+        bucky_bank.match_vault_balance = match_vault_balance;
+    }
+
+    bucky_bank.match_ratio_bps = match_ratio_bps;
+    bucky_bank.match_period_cap = match_period_cap;
+    bucky_bank.match_period_ms = match_period_ms;
+
+    emit!(MatchingRuleUpdated {
+        bucky_bank_id: bucky_bank.key(),
+        parent: sender,
+        match_ratio_bps,
+        match_period_cap,
+        match_period_ms,
+        match_vault_balance: bucky_bank.match_vault_balance,
+        created_at_ms: current_time_ms,
+        mint: bucky_bank.config.mint,
+        decimals: bucky_bank.config.decimals,
+    });
+
+    Ok(())
+}
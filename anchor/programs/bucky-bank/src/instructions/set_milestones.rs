@@ -0,0 +1,100 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, BuckyBankStatus, Milestone, MilestoneInput},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetMilestones<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub parent: Signer<'info>,
+}
+
+/// 家长设置存钱罐的里程碑
+///
+/// 里程碑金额必须大于 0、严格递增且不超过目标金额，传入空列表清除全部里程碑。
+/// 金额未变的里程碑保留原达成时间；当前余额已达到的新里程碑立即记为达成。
+pub fn _set_milestones(
+    ctx: Context<SetMilestones>,
+    milestones: Vec<MilestoneInput>,
+) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+    require!(
+        bucky_bank.status == BuckyBankStatus::Active,
+        ErrorCode::BankNotActive
+    );
+    require!(
+        milestones.len() <= MAX_MILESTONES,
+        ErrorCode::InvalidMilestones
+    );
+    for (i, milestone) in milestones.iter().enumerate() {
+        require!(
+            milestone.amount > 0
+                && milestone.amount <= bucky_bank.config.target_amount
+                && (i == 0 || milestone.amount > milestones[i - 1].amount),
+            ErrorCode::InvalidMilestones
+        );
+        require!(
+            milestone.label.len() <= MAX_MILESTONE_LABEL_LENGTH,
+            ErrorCode::InvalidMilestones
+        );
+    }
+
+    bucky_bank.milestones = milestones
+        .into_iter()
+        .map(|milestone| {
+            let reached_at_ms = bucky_bank
+                .milestones
+                .iter()
+                .find(|old| old.amount == milestone.amount)
+                .map_or(0, |old| old.reached_at_ms);
+            Milestone {
+                amount: milestone.amount,
+                label: milestone.label,
+                reached_at_ms,
+            }
+        })
+        .collect();
+    let reached = bucky_bank.reach_milestones(current_time_ms);
+
+    emit!(MilestonesUpdated {
+        bucky_bank_id: bucky_bank.key(),
+        parent: sender,
+        milestones: bucky_bank.milestones.clone(),
+        created_at_ms: current_time_ms,
+    });
+
+    for index in reached {
+        let milestone = &bucky_bank.milestones[index as usize];
+        emit!(MilestoneReached {
+            bucky_bank_id: bucky_bank.key(),
+            milestone_index: index,
+            amount: milestone.amount,
+            label: milestone.label.clone(),
+            current_balance: bucky_bank.current_balance,
+            target_amount: bucky_bank.config.target_amount,
+            reached_by: sender,
+            created_at_ms: current_time_ms,
+            mint: bucky_bank.config.mint,
+            decimals: bucky_bank.config.decimals,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::BankGlobalStatsInfo,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        has_one = admin @ ErrorCode::NotAdmin,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    pub admin: Signer<'info>,
+}
+
+/// 管理员暂停或恢复程序（紧急停止）
+///
+/// 暂停期间所有用户指令都会被拒绝，管理员指令不受影响。
+pub fn _set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bank_global_stats = &mut ctx.accounts.bank_global_stats;
+    bank_global_stats.paused = paused;
+
+    emit!(PauseStateChanged {
+        admin: ctx.accounts.admin.key(),
+        paused,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
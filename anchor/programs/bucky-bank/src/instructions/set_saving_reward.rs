@@ -0,0 +1,120 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, BuckyBankStatus},
+    utils::{deposit_into_vault, payout_from_vault},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct SetSavingReward<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    #[account(mut)]
+    pub parent: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_VAULT_SEED, bucky_bank.key().as_ref()],
+        bump = bucky_bank.reward_vault_bump,
+    )]
+    pub reward_vault: SystemAccount<'info>,
+
+    /// 以下账户仅 SPL Token 存钱罐需要
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = reward_vault,
+        associated_token::token_program = token_program,
+    )]
+    pub reward_vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = parent,
+        token::token_program = token_program,
+    )]
+    pub parent_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// 家长调整达成目标奖励
+///
+/// 奖励金库始终按 `reward_amount` 足额托管：调高时由家长补足差额，调低时差额退回家长。
+/// 只能在存钱罐进行中且奖励未领取时调整。
+pub fn _set_saving_reward(ctx: Context<SetSavingReward>, reward_amount: u64) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+    require!(
+        bucky_bank.status == BuckyBankStatus::Active,
+        ErrorCode::BankNotActive
+    );
+    require!(!bucky_bank.reward_claimed, ErrorCode::RewardAlreadyClaimed);
+
+    let old_reward_amount = bucky_bank.reward_amount;
+
+    if reward_amount > old_reward_amount {
+        let received = deposit_into_vault(
+            bucky_bank.config.mint,
+            &ctx.accounts.parent,
+            &ctx.accounts.reward_vault,
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.parent_token_account.as_deref(),
+            ctx.accounts.reward_vault_token_account.as_deref_mut(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+            reward_amount - old_reward_amount,
+        )?;
+        bucky_bank.reward_amount = old_reward_amount
+            .checked_add(received)
+            .ok_or(ErrorCode::Overflow)?;
+    } else if reward_amount < old_reward_amount {
+        payout_from_vault(
+            REWARD_VAULT_SEED,
+            &bucky_bank.key(),
+            bucky_bank.reward_vault_bump,
+            bucky_bank.config.mint,
+            &ctx.accounts.reward_vault,
+            &ctx.accounts.parent.to_account_info(),
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.reward_vault_token_account.as_deref(),
+            ctx.accounts.parent_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+            old_reward_amount - reward_amount,
+        )?;
+        bucky_bank.reward_amount = reward_amount;
+    }
+
+    emit!(SavingRewardUpdated {
+        bucky_bank_id: bucky_bank.key(),
+        parent: sender,
+        old_reward_amount,
+        new_reward_amount: bucky_bank.reward_amount,
+        created_at_ms: current_time_ms,
+        mint: bucky_bank.config.mint,
+        decimals: bucky_bank.config.decimals,
+    });
+
+    Ok(())
+}
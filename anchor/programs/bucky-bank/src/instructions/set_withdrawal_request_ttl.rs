@@ -0,0 +1,52 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetWithdrawalRequestTtl<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub parent: Signer<'info>,
+}
+
+/// 家长设置取款请求的有效期
+///
+/// 只影响之后创建的请求，已有请求保留创建时的过期时间。
+pub fn _set_withdrawal_request_ttl(
+    ctx: Context<SetWithdrawalRequestTtl>,
+    ttl_days: u64,
+) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+    require!(
+        ttl_days > 0 && ttl_days <= MAX_WITHDRAWAL_REQUEST_TTL_DAYS,
+        ErrorCode::InvalidWithdrawalRequestTtl
+    );
+
+    bucky_bank.withdrawal_request_ttl_ms = ttl_days * DAY_MS;
+
+    emit!(WithdrawalRequestTtlUpdated {
+        bucky_bank_id: bucky_bank.key(),
+        parent: sender,
+        withdrawal_request_ttl_ms: bucky_bank.withdrawal_request_ttl_ms,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
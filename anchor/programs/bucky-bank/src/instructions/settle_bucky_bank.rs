@@ -0,0 +1,69 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, BuckyBankStatus, GlobalStatsDelta},
+    utils::emit_global_stats,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SettleBuckyBank<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub signer: Signer<'info>,
+}
+
+/// 截止结算：存钱罐到期后按余额判定完成或失败
+///
+/// 任何人都可以在截止时间后调用。余额达到目标记为已完成，否则记为已失败。
+pub fn _settle_bucky_bank(ctx: Context<SettleBuckyBank>) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(
+        bucky_bank.status == BuckyBankStatus::Active,
+        ErrorCode::BankNotActive
+    );
+    require!(
+        current_time_ms > bucky_bank.config.deadline_ms,
+        ErrorCode::DeadlineNotReached
+    );
+
+    // 结算后不再计息，先把截止时间前的利息记入应付利息
+    bucky_bank.checkpoint_interest(current_time_ms);
+
+    bucky_bank.status = if bucky_bank.current_balance >= bucky_bank.config.target_amount {
+        BuckyBankStatus::Completed
+    } else {
+        BuckyBankStatus::Failed
+    };
+
+    // 发出全局统计增量
+    let mut stats = GlobalStatsDelta::default();
+    stats.record_status_change(BuckyBankStatus::Active, bucky_bank.status);
+    emit_global_stats(stats, bucky_bank.key(), current_time_ms);
+
+    emit!(BuckyBankSettled {
+        bucky_bank_id: bucky_bank.key(),
+        status: bucky_bank.status,
+        current_balance: bucky_bank.current_balance,
+        target_amount: bucky_bank.config.target_amount,
+        deadline_ms: bucky_bank.config.deadline_ms,
+        settled_by: ctx.accounts.signer.key(),
+        created_at_ms: current_time_ms,
+        mint: bucky_bank.config.mint,
+        decimals: bucky_bank.config.decimals,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, BuckyBankStatus, GlobalStatsDelta},
+    utils::emit_global_stats,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct SyncBalance<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(
+        mut,
+        has_one = parent @ ErrorCode::NotParent,
+    )]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    #[account(
+        seeds = [VAULT_SEED, bucky_bank.key().as_ref()],
+        bump = bucky_bank.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// 以下账户仅 SPL Token 存钱罐需要
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub parent: Signer<'info>,
+}
+
+/// 对账：以金库实际余额校准 `current_balance`
+///
+/// 只有家长可以调用，避免他人直接向金库转账再对账来绕过存款人策略与存款限额。
+/// 原生 SOL 存钱罐以金库 lamports 扣除免租金最低余额为准，SPL Token 存钱罐以金库 Token 账户余额为准。
+/// 截止时间之后对账只校准余额，不改变状态，结果仍由结算决定。
+pub fn _sync_balance(ctx: Context<SyncBalance>) -> Result<()> {
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    let vault_balance = match bucky_bank.config.mint {
+        None => {
+            let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+            ctx.accounts.vault.lamports().saturating_sub(rent_exempt_minimum)
+        }
+        Some(bank_mint) => {
+            let (Some(mint), Some(vault_token_account)) = (
+                ctx.accounts.mint.as_ref(),
+                ctx.accounts.vault_token_account.as_ref(),
+            ) else {
+                return err!(ErrorCode::MissingTokenAccounts);
+            };
+            require_keys_eq!(mint.key(), bank_mint, ErrorCode::InvalidMint);
+
+            vault_token_account.amount
+        }
+    };
+
+    // 余额变化前先按原余额结算利息
+    bucky_bank.checkpoint_interest(current_time_ms);
+
+    let previous_balance = bucky_bank.current_balance;
+    bucky_bank.current_balance = vault_balance;
+
+    // 校准后可能达到目标，截止时间之后交由结算判断
+    let mut stats = GlobalStatsDelta::default();
+    if bucky_bank.status == BuckyBankStatus::Active
+        && current_time_ms < bucky_bank.config.deadline_ms
+        && bucky_bank.current_balance >= bucky_bank.config.target_amount
+    {
+        bucky_bank.status = BuckyBankStatus::Completed;
+        stats.record_status_change(BuckyBankStatus::Active, BuckyBankStatus::Completed);
+    }
+    stats.record_balance_sync(previous_balance, vault_balance, bucky_bank.config.is_native());
+    emit_global_stats(stats, bucky_bank.key(), current_time_ms);
+
+    emit!(BalanceReconciled {
+        bucky_bank_id: bucky_bank.key(),
+        previous_balance,
+        current_balance: bucky_bank.current_balance,
+        synced_by: ctx.accounts.parent.key(),
+        created_at_ms: current_time_ms,
+        mint: bucky_bank.config.mint,
+        decimals: bucky_bank.config.decimals,
+    });
+
+    Ok(())
+}
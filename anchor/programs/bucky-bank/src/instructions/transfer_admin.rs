@@ -0,0 +1,85 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::BankGlobalStatsInfo,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeAdminTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        has_one = admin @ ErrorCode::NotAdmin,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    pub admin: Signer<'info>,
+}
+
+/// 管理员提名新管理员（两步转移的第一步）
+///
+/// 传入 None 撤销尚未接受的提名。
+pub fn _propose_admin_transfer(
+    ctx: Context<ProposeAdminTransfer>,
+    new_admin: Option<Pubkey>,
+) -> Result<()> {
+    let sender = ctx.accounts.admin.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    if let Some(new_admin) = new_admin {
+        require!(
+            new_admin != Pubkey::default() && new_admin != sender,
+            ErrorCode::InvalidNewAddress
+        );
+    }
+
+    ctx.accounts.bank_global_stats.pending_admin = new_admin;
+
+    emit!(AdminTransferProposed {
+        admin: sender,
+        pending_admin: new_admin,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdminTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    pub new_admin: Signer<'info>,
+}
+
+/// 新管理员接受提名（两步转移的第二步）
+pub fn _accept_admin_transfer(ctx: Context<AcceptAdminTransfer>) -> Result<()> {
+    let sender = ctx.accounts.new_admin.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bank_global_stats = &mut ctx.accounts.bank_global_stats;
+
+    require!(
+        bank_global_stats.pending_admin == Some(sender),
+        ErrorCode::NotPendingAdmin
+    );
+
+    let old_admin = bank_global_stats.admin;
+    bank_global_stats.admin = sender;
+    bank_global_stats.pending_admin = None;
+
+    emit!(AdminTransferred {
+        old_admin,
+        new_admin: sender,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, UserBuckyBanksInfo, UserBuckyBanksPage},
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeParentTransfer<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub parent: Signer<'info>,
+}
+
+/// 家长提名新家长（两步转移的第一步）
+///
+/// 传入 None 撤销尚未接受的提名。
+pub fn _propose_parent_transfer(
+    ctx: Context<ProposeParentTransfer>,
+    new_parent: Option<Pubkey>,
+) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+    if let Some(new_parent) = new_parent {
+        require!(
+            new_parent != Pubkey::default() && new_parent != sender,
+            ErrorCode::InvalidNewAddress
+        );
+    }
+
+    bucky_bank.pending_parent = new_parent;
+
+    emit!(ParentTransferProposed {
+        bucky_bank_id: bucky_bank.key(),
+        parent: sender,
+        pending_parent: new_parent,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptParentTransfer<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    #[account(
+        mut,
+        seeds = [USER_BUCKY_BANKS_SEED, bucky_bank.parent.as_ref()],
+        bump,
+    )]
+    pub old_parent_bucky_banks: Box<Account<'info, UserBuckyBanksInfo>>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKY_BANK_INDEX_PAGE_SEED,
+            old_parent_bucky_banks.key().as_ref(),
+            bucky_bank.parent_index_page.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub old_parent_bucky_banks_page: Box<Account<'info, UserBuckyBanksPage>>,
+
+    #[account(
+        init_if_needed,
+        payer = new_parent,
+        space = ANCHOR_DISCRIMINATOR_SIZE + UserBuckyBanksInfo::INIT_SPACE,
+        seeds = [USER_BUCKY_BANKS_SEED, new_parent.key().as_ref()],
+        bump,
+    )]
+    pub new_parent_bucky_banks: Box<Account<'info, UserBuckyBanksInfo>>,
+
+    #[account(
+        init_if_needed,
+        payer = new_parent,
+        space = ANCHOR_DISCRIMINATOR_SIZE + UserBuckyBanksPage::INIT_SPACE,
+        seeds = [
+            BUCKY_BANK_INDEX_PAGE_SEED,
+            new_parent_bucky_banks.key().as_ref(),
+            new_parent_bucky_banks.current_page.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub new_parent_bucky_banks_page: Box<Account<'info, UserBuckyBanksPage>>,
+
+    #[account(mut)]
+    pub new_parent: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// 新家长接受提名（两步转移的第二步）
+///
+/// 存钱罐从原家长的索引移到新家长的索引；原家长若在监护人列表中，总是被移除并由新家长替换，
+/// 新家长已是监护人时只移除原家长，批准门槛随之不超过监护人数。
+pub fn _accept_parent_transfer(ctx: Context<AcceptParentTransfer>) -> Result<()> {
+    let sender = ctx.accounts.new_parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+    let bucky_bank_id = bucky_bank.key();
+
+    require!(
+        bucky_bank.pending_parent == Some(sender),
+        ErrorCode::NotPendingParent
+    );
+
+    let old_parent = bucky_bank.parent;
+    bucky_bank.parent = sender;
+    bucky_bank.pending_parent = None;
+
+    // 原家长不再保留监护人投票权，由新家长替换
+    bucky_bank.replace_guardian(old_parent, sender);
+
+    // 从原家长索引移到新家长索引
+    ctx.accounts
+        .old_parent_bucky_banks
+        .remove(&mut ctx.accounts.old_parent_bucky_banks_page, bucky_bank_id);
+
+    let new_parent_bucky_banks_key = ctx.accounts.new_parent_bucky_banks.key();
+    ctx.accounts.bucky_bank.parent_index_page = ctx.accounts.new_parent_bucky_banks.append(
+        new_parent_bucky_banks_key,
+        sender,
+        &mut ctx.accounts.new_parent_bucky_banks_page,
+        bucky_bank_id,
+    );
+
+    emit!(ParentTransferred {
+        bucky_bank_id,
+        old_parent,
+        new_parent: sender,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
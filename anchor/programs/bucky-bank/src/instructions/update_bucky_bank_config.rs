@@ -0,0 +1,113 @@
+use crate::{
+    constants::*,
+    error_code::BuckyBankError as ErrorCode,
+    events::*,
+    state::{BankGlobalStatsInfo, BuckyBankInfo, BuckyBankStatus, GlobalStatsDelta},
+    utils::emit_global_stats,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateBuckyBankConfig<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
+    #[account(mut)]
+    pub bucky_bank: Account<'info, BuckyBankInfo>,
+
+    pub parent: Signer<'info>,
+}
+
+/// 家长修改存钱罐配置
+///
+/// 每个参数为 None 时保持不变。截止时间只能延后；修改目标金额后重新判定完成状态，
+/// 奖励已领取后不能再修改目标金额。截止时间过后只能改名，冻结期间不能修改。
+pub fn _update_bucky_bank_config(
+    ctx: Context<UpdateBuckyBankConfig>,
+    name: Option<String>,
+    target_amount: Option<u64>,
+    deadline_ms: Option<u64>,
+) -> Result<()> {
+    let sender = ctx.accounts.parent.key();
+    let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
+
+    let bucky_bank = &mut ctx.accounts.bucky_bank;
+
+    require!(sender == bucky_bank.parent, ErrorCode::NotParent);
+    require!(
+        bucky_bank.status != BuckyBankStatus::Failed,
+        ErrorCode::BankFailed
+    );
+    require!(!bucky_bank.frozen, ErrorCode::BankFrozen);
+
+    // 截止时间和状态变化会影响计息，先按原配置结算利息
+    bucky_bank.checkpoint_interest(current_time_ms);
+
+    let old_name = bucky_bank.config.name.clone();
+    let old_target_amount = bucky_bank.config.target_amount;
+    let old_deadline_ms = bucky_bank.config.deadline_ms;
+    let old_status = bucky_bank.status;
+    // 截止时间过后存钱罐等待结算，目标和截止时间不能再修改
+    let deadline_passed = current_time_ms > old_deadline_ms;
+
+    if let Some(name) = name {
+        require!(name.len() <= MAX_NAME_LENGTH, ErrorCode::InvalidName);
+        bucky_bank.config.name = name;
+    }
+
+    if let Some(deadline_ms) = deadline_ms {
+        require!(!deadline_passed, ErrorCode::DeadlinePassed);
+        require!(
+            deadline_ms > old_deadline_ms && deadline_ms > current_time_ms,
+            ErrorCode::InvalidDeadline
+        );
+        bucky_bank.config.deadline_ms = deadline_ms;
+    }
+
+    if let Some(target_amount) = target_amount {
+        require!(!deadline_passed, ErrorCode::DeadlinePassed);
+        require!(target_amount > 0, ErrorCode::InvalidAmount);
+        require!(!bucky_bank.reward_claimed, ErrorCode::RewardAlreadyClaimed);
+        // 里程碑按金额升序排列，目标不能低于最高的里程碑
+        if let Some(milestone) = bucky_bank.milestones.last() {
+            require!(
+                target_amount >= milestone.amount,
+                ErrorCode::TargetBelowMilestone
+            );
+        }
+        bucky_bank.config.target_amount = target_amount;
+
+        // 按新目标重新判定：达到目标记为已完成，调高目标后未达到则恢复进行中
+        bucky_bank.status = if bucky_bank.current_balance >= target_amount {
+            BuckyBankStatus::Completed
+        } else {
+            BuckyBankStatus::Active
+        };
+    }
+
+    if bucky_bank.status != old_status {
+        let mut stats = GlobalStatsDelta::default();
+        stats.record_status_change(old_status, bucky_bank.status);
+        emit_global_stats(stats, bucky_bank.key(), current_time_ms);
+    }
+
+    emit!(BuckyBankConfigUpdated {
+        bucky_bank_id: bucky_bank.key(),
+        parent: sender,
+        old_name,
+        new_name: bucky_bank.config.name.clone(),
+        old_target_amount,
+        new_target_amount: bucky_bank.config.target_amount,
+        old_deadline_ms,
+        new_deadline_ms: bucky_bank.config.deadline_ms,
+        old_status,
+        new_status: bucky_bank.status,
+        created_at_ms: current_time_ms,
+    });
+
+    Ok(())
+}
@@ -1,37 +1,101 @@
 use crate::{
+    constants::*,
     error_code::BuckyBankError as ErrorCode,
     events::*,
-    state::{BuckyBankInfo, WithdrawalRequestInfo, WithdrawalStatus},
+    state::{BankGlobalStatsInfo, BuckyBankInfo, GlobalStatsDelta, WithdrawalRequestInfo, WithdrawalStatus},
+    utils::{emit_global_stats, payout_from_vault},
 };
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::system_instruction;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
 
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
+    #[account(
+        seeds = [BANK_GLOBAL_STATS_SEED],
+        bump,
+        constraint = !bank_global_stats.paused @ ErrorCode::ProgramPaused,
+    )]
+    pub bank_global_stats: Account<'info, BankGlobalStatsInfo>,
+
     #[account(mut)]
     pub bucky_bank: Account<'info, BuckyBankInfo>,
 
-    #[account(mut, close = bucky_bank)]
+    /// 提取后关闭请求账户，租金退还给孩子
+    #[account(mut, close = child)]
     pub withdrawal_request: Account<'info, WithdrawalRequestInfo>,
 
     #[account(mut)]
     pub child: Signer<'info>,
 
+    /// 金库 PDA：原生 SOL 从此转出，SPL Token 转出时作为签名者
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, bucky_bank.key().as_ref()],
+        bump = bucky_bank.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+
+    /// 以下账户仅 SPL Token 存钱罐需要
+    pub mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = child,
+        associated_token::mint = mint,
+        associated_token::authority = child,
+        associated_token::token_program = token_program,
+    )]
+    pub child_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// 罚金收款地址，紧急取款有罚金时需要
+    /// CHECK: 地址由取款请求记录的罚金收款地址约束，只接收转账
+    #[account(
+        mut,
+        address = withdrawal_request.penalty_recipient @ ErrorCode::InvalidRecipient,
+    )]
+    pub penalty_recipient: Option<UncheckedAccount<'info>>,
+
+    /// 罚金收款地址的 Token 账户，SPL Token 存钱罐紧急取款有罚金时需要
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub penalty_recipient_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
     pub system_program: Program<'info, System>,
 }
 
 /// 小孩提取存款（若审批通过）
-/// 
+///
 /// 此指令允许孩子在取款请求被家长批准后提取资金。
-/// 
+///
 /// # 参数
 /// 无额外参数
-/// 
+///
 /// # 验证
 /// - 调用者必须是孩子地址
+/// - 存钱罐未被家长冻结
 /// - 取款请求必须属于该存钱罐
-/// - 取款请求必须已被批准
+/// - 取款请求必须处于已批准状态（由状态机校验，提取后记为已完成并关闭账户）
 /// - 存钱罐余额必须足够
+///
+/// 紧急取款按请求记录的比例从批准金额中扣除罚金，付给家长或家长指定的慈善地址。
 pub fn _withdraw(ctx: Context<Withdraw>) -> Result<()> {
     let sender = ctx.accounts.child.key();
     let current_time_ms = Clock::get()?.unix_timestamp as u64 * 1000;
@@ -44,18 +108,24 @@ pub fn _withdraw(ctx: Context<Withdraw>) -> Result<()> {
         sender == bucky_bank.config.child_address,
         ErrorCode::NotChild
     );
+    require!(!bucky_bank.frozen, ErrorCode::BankFrozen);
+    require!(
+        withdrawal_request.requester == sender,
+        ErrorCode::NotChildForWithdrawal
+    );
 
     require!(
         withdrawal_request.bucky_bank_id == bucky_bank.key(),
         ErrorCode::RequestNotFound
     );
 
-    require!(
-        withdrawal_request.approved_by == bucky_bank.parent,
-        ErrorCode::NotParent
-    );
+    // 只有已批准的请求可以提取，迁移到已完成
+    withdrawal_request.transition_to(WithdrawalStatus::Completed)?;
+    withdrawal_request.completed_at_ms = current_time_ms;
+    bucky_bank.open_request_count = bucky_bank.open_request_count.saturating_sub(1);
 
-    let amount = withdrawal_request.amount;
+    // 按批准金额提取，家长可能批准了少于请求的金额
+    let amount = withdrawal_request.approved_amount;
 
     // 验证金额有效性
     require!(amount > 0, ErrorCode::InvalidWithdrawalAmount);
@@ -64,20 +134,62 @@ pub fn _withdraw(ctx: Context<Withdraw>) -> Result<()> {
         ErrorCode::InsufficientFunds
     );
 
+    // 余额变化前先按原余额结算利息
+    bucky_bank.checkpoint_interest(current_time_ms);
+
     // 更新存钱罐余额
     bucky_bank.current_balance = bucky_bank.current_balance
         .checked_sub(amount)
         .ok_or(ErrorCode::Overflow)?;
 
-    **bucky_bank
-        .to_account_info()
-        .try_borrow_mut_lamports()? -= amount;
-
-    **ctx
-        .accounts
-        .child
-        .to_account_info()
-        .try_borrow_mut_lamports()? += amount;
+    let penalty_amount = withdrawal_request.penalty_amount();
+    let payout_amount = amount - penalty_amount;
+
+    if payout_amount > 0 {
+        payout_from_vault(
+            VAULT_SEED,
+            &bucky_bank.key(),
+            bucky_bank.vault_bump,
+            bucky_bank.config.mint,
+            &ctx.accounts.vault,
+            &ctx.accounts.child.to_account_info(),
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.vault_token_account.as_deref(),
+            ctx.accounts.child_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+            payout_amount,
+        )?;
+    }
+
+    // 紧急取款罚金付给罚金收款地址
+    if penalty_amount > 0 {
+        let Some(penalty_recipient) = ctx.accounts.penalty_recipient.as_ref() else {
+            return err!(ErrorCode::MissingPenaltyRecipient);
+        };
+        if let Some(to) = ctx.accounts.penalty_recipient_token_account.as_deref() {
+            require_keys_eq!(to.owner, penalty_recipient.key(), ErrorCode::InvalidRecipient);
+        }
+        payout_from_vault(
+            VAULT_SEED,
+            &bucky_bank.key(),
+            bucky_bank.vault_bump,
+            bucky_bank.config.mint,
+            &ctx.accounts.vault,
+            &penalty_recipient.to_account_info(),
+            ctx.accounts.mint.as_deref(),
+            ctx.accounts.vault_token_account.as_deref(),
+            ctx.accounts.penalty_recipient_token_account.as_deref(),
+            ctx.accounts.token_program.as_ref(),
+            &ctx.accounts.system_program,
+            penalty_amount,
+        )?;
+    }
+
+    // 发出全局统计增量
+    let mut stats = GlobalStatsDelta::default();
+    stats.record_withdrawal(amount, bucky_bank.config.is_native());
+    emit_global_stats(stats, bucky_bank.key(), current_time_ms);
 
     // 保存取款请求的 key 用于事件发送
     let request_id = withdrawal_request.key();
@@ -90,6 +202,10 @@ pub fn _withdraw(ctx: Context<Withdraw>) -> Result<()> {
         left_balance: bucky_bank.current_balance,
         withdrawer: sender,
         created_at_ms: current_time_ms,
+        mint: bucky_bank.config.mint,
+        decimals: bucky_bank.config.decimals,
+        penalty_amount,
+        penalty_recipient: (penalty_amount > 0).then_some(withdrawal_request.penalty_recipient),
     });
 
     Ok(())
@@ -6,6 +6,9 @@ pub mod error_code;
 pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
+
+use state::{DepositorPolicy, LockMode, MilestoneInput};
 
 use instructions::initialize_bank_global_stats::*;
 use instructions::create_bucky_bank::*;
@@ -13,6 +16,35 @@ use instructions::deposit::*;
 use instructions::request_withdrawal::*;
 use instructions::approve_withdrawal::*;
 use instructions::withdraw::*;
+use instructions::cancel_withdrawal::*;
+use instructions::sync_balance::*;
+use instructions::set_saving_reward::*;
+use instructions::claim_saving_rewards::*;
+use instructions::settle_bucky_bank::*;
+use instructions::close_bucky_bank::*;
+use instructions::set_depositor_policy::*;
+use instructions::set_guardians::*;
+use instructions::set_auto_approval::*;
+use instructions::update_bucky_bank_config::*;
+use instructions::transfer_parent::*;
+use instructions::rotate_child::*;
+use instructions::set_paused::*;
+use instructions::transfer_admin::*;
+use instructions::set_matching_rule::*;
+use instructions::set_interest_rate::*;
+use instructions::accrue_interest::*;
+use instructions::set_milestones::*;
+use instructions::expire_withdrawal_request::*;
+use instructions::set_withdrawal_request_ttl::*;
+use instructions::set_default_deposit_limits::*;
+use instructions::set_deposit_limits::*;
+use instructions::set_lock_mode::*;
+use instructions::freeze_bucky_bank::*;
+use instructions::emergency_withdraw::*;
+use instructions::close_withdrawal_request::*;
+use instructions::migrate_bank_global_stats::*;
+use instructions::close_legacy_bucky_bank::*;
+use instructions::close_legacy_withdrawal_request::*;
 
 declare_id!("2N4emW88bMtPELSz6s62oTt48enm2yo8KbSdk9BeGPBG");
 
@@ -30,34 +62,235 @@ pub mod bucky_bank {
         target_amount: u64,
         duration_days: u64,
         child_address: Pubkey,
+        reward_amount: u64,
     ) -> Result<()> {
-        _create_bucky_bank(ctx, name, target_amount, duration_days, child_address)
+        _create_bucky_bank(ctx, name, target_amount, duration_days, child_address, reward_amount)
     }
 
     pub fn deposit(
         ctx: Context<Deposit>,
         deposit_balance: u64,
+        message: Option<String>,
     ) -> Result<()> {
-        _deposit(ctx, deposit_balance)
+        _deposit(ctx, deposit_balance, message)
     }
 
     pub fn request_withdrawal(
         ctx: Context<RequestWithdrawal>,
         amount: u64,
         reason: String,
+        emergency: bool,
     ) -> Result<()> {
-        _request_withdrawal(ctx, amount, reason)
+        _request_withdrawal(ctx, amount, reason, emergency)
     }
 
     pub fn approve_withdrawal(
         ctx: Context<ApproveWithdrawal>,
         approve: bool,
         reason: String,
+        approved_amount: Option<u64>,
     ) -> Result<()> {
-        _approve_withdrawal(ctx, approve, reason)
+        _approve_withdrawal(ctx, approve, reason, approved_amount)
     }
 
     pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
         _withdraw(ctx)
     }
+
+    pub fn cancel_withdrawal(ctx: Context<CancelWithdrawal>) -> Result<()> {
+        _cancel_withdrawal(ctx)
+    }
+
+    pub fn sync_balance(ctx: Context<SyncBalance>) -> Result<()> {
+        _sync_balance(ctx)
+    }
+
+    pub fn set_saving_reward(
+        ctx: Context<SetSavingReward>,
+        reward_amount: u64,
+    ) -> Result<()> {
+        _set_saving_reward(ctx, reward_amount)
+    }
+
+    pub fn claim_saving_rewards(ctx: Context<ClaimSavingRewards>) -> Result<()> {
+        _claim_saving_rewards(ctx)
+    }
+
+    pub fn settle_bucky_bank(ctx: Context<SettleBuckyBank>) -> Result<()> {
+        _settle_bucky_bank(ctx)
+    }
+
+    pub fn close_bucky_bank(ctx: Context<CloseBuckyBank>) -> Result<()> {
+        _close_bucky_bank(ctx)
+    }
+
+    pub fn set_depositor_policy(
+        ctx: Context<SetDepositorPolicy>,
+        depositor_policy: DepositorPolicy,
+        allowed_depositors: Vec<Pubkey>,
+    ) -> Result<()> {
+        _set_depositor_policy(ctx, depositor_policy, allowed_depositors)
+    }
+
+    pub fn set_guardians(
+        ctx: Context<SetGuardians>,
+        guardians: Vec<Pubkey>,
+        approval_threshold: u8,
+    ) -> Result<()> {
+        _set_guardians(ctx, guardians, approval_threshold)
+    }
+
+    pub fn set_auto_approval(
+        ctx: Context<SetAutoApproval>,
+        auto_approve_limit: u64,
+        daily_cap: u64,
+        weekly_cap: u64,
+    ) -> Result<()> {
+        _set_auto_approval(ctx, auto_approve_limit, daily_cap, weekly_cap)
+    }
+
+    pub fn update_bucky_bank_config(
+        ctx: Context<UpdateBuckyBankConfig>,
+        name: Option<String>,
+        target_amount: Option<u64>,
+        deadline_ms: Option<u64>,
+    ) -> Result<()> {
+        _update_bucky_bank_config(ctx, name, target_amount, deadline_ms)
+    }
+
+    pub fn propose_parent_transfer(
+        ctx: Context<ProposeParentTransfer>,
+        new_parent: Option<Pubkey>,
+    ) -> Result<()> {
+        _propose_parent_transfer(ctx, new_parent)
+    }
+
+    pub fn accept_parent_transfer(ctx: Context<AcceptParentTransfer>) -> Result<()> {
+        _accept_parent_transfer(ctx)
+    }
+
+    pub fn rotate_child<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RotateChild<'info>>,
+        new_child: Pubkey,
+    ) -> Result<()> {
+        _rotate_child(ctx, new_child)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        _set_paused(ctx, paused)
+    }
+
+    pub fn propose_admin_transfer(
+        ctx: Context<ProposeAdminTransfer>,
+        new_admin: Option<Pubkey>,
+    ) -> Result<()> {
+        _propose_admin_transfer(ctx, new_admin)
+    }
+
+    pub fn accept_admin_transfer(ctx: Context<AcceptAdminTransfer>) -> Result<()> {
+        _accept_admin_transfer(ctx)
+    }
+
+    pub fn set_matching_rule(
+        ctx: Context<SetMatchingRule>,
+        match_ratio_bps: u16,
+        match_period_cap: u64,
+        match_period_days: u64,
+        match_vault_balance: u64,
+    ) -> Result<()> {
+        _set_matching_rule(
+            ctx,
+            match_ratio_bps,
+            match_period_cap,
+            match_period_days,
+            match_vault_balance,
+        )
+    }
+
+    pub fn set_interest_rate(
+        ctx: Context<SetInterestRate>,
+        interest_rate_bps: u16,
+        interest_vault_balance: u64,
+    ) -> Result<()> {
+        _set_interest_rate(ctx, interest_rate_bps, interest_vault_balance)
+    }
+
+    pub fn accrue_interest(ctx: Context<AccrueInterest>) -> Result<()> {
+        _accrue_interest(ctx)
+    }
+
+    pub fn set_milestones(
+        ctx: Context<SetMilestones>,
+        milestones: Vec<MilestoneInput>,
+    ) -> Result<()> {
+        _set_milestones(ctx, milestones)
+    }
+
+    pub fn expire_withdrawal_request(ctx: Context<ExpireWithdrawalRequest>) -> Result<()> {
+        _expire_withdrawal_request(ctx)
+    }
+
+    pub fn set_withdrawal_request_ttl(
+        ctx: Context<SetWithdrawalRequestTtl>,
+        ttl_days: u64,
+    ) -> Result<()> {
+        _set_withdrawal_request_ttl(ctx, ttl_days)
+    }
+
+    pub fn set_default_deposit_limits(
+        ctx: Context<SetDefaultDepositLimits>,
+        default_min_deposit: u64,
+        default_max_deposit: u64,
+    ) -> Result<()> {
+        _set_default_deposit_limits(ctx, default_min_deposit, default_max_deposit)
+    }
+
+    pub fn set_deposit_limits(
+        ctx: Context<SetDepositLimits>,
+        min_deposit: u64,
+        max_deposit: u64,
+    ) -> Result<()> {
+        _set_deposit_limits(ctx, min_deposit, max_deposit)
+    }
+
+    pub fn set_lock_mode(
+        ctx: Context<SetLockMode>,
+        lock_mode: LockMode,
+        emergency_penalty_bps: u16,
+        penalty_recipient: Option<Pubkey>,
+    ) -> Result<()> {
+        _set_lock_mode(ctx, lock_mode, emergency_penalty_bps, penalty_recipient)
+    }
+
+    pub fn freeze_bucky_bank(ctx: Context<FreezeBuckyBank>, reason: String) -> Result<()> {
+        _freeze_bucky_bank(ctx, reason)
+    }
+
+    pub fn unfreeze_bucky_bank(ctx: Context<UnfreezeBuckyBank>, reason: String) -> Result<()> {
+        _unfreeze_bucky_bank(ctx, reason)
+    }
+
+    pub fn emergency_withdraw(
+        ctx: Context<EmergencyWithdraw>,
+        amount: u64,
+        reason: String,
+    ) -> Result<()> {
+        _emergency_withdraw(ctx, amount, reason)
+    }
+
+    pub fn close_withdrawal_request(ctx: Context<CloseWithdrawalRequest>) -> Result<()> {
+        _close_withdrawal_request(ctx)
+    }
+
+    pub fn migrate_bank_global_stats(ctx: Context<MigrateBankGlobalStats>) -> Result<()> {
+        _migrate_bank_global_stats(ctx)
+    }
+
+    pub fn close_legacy_bucky_bank(ctx: Context<CloseLegacyBuckyBank>) -> Result<()> {
+        _close_legacy_bucky_bank(ctx)
+    }
+
+    pub fn close_legacy_withdrawal_request(ctx: Context<CloseLegacyWithdrawalRequest>) -> Result<()> {
+        _close_legacy_withdrawal_request(ctx)
+    }
 }
@@ -1,10 +1,120 @@
 use anchor_lang::prelude::*;
 
+use crate::state::BuckyBankStatus;
+
+/// 全局配置账户
+///
+/// 前四个字段是最初上线的布局，保持原有顺序以兼容已部署的账户；新字段只能追加在末尾，
+/// 已部署的账户需先执行 `migrate_bank_global_stats` 扩容。
+/// 全局统计不在本账户上累计，而是由各指令通过 `GlobalStatsUpdated` 事件发出增量，
+/// 避免每笔存取款都写锁该账户，见 `GlobalStatsDelta`。
 #[account]
 #[derive(InitSpace)]
 pub struct BankGlobalStatsInfo {
-    pub total_bucky_banks: u64, // 存钱罐数量
-    pub total_deposits: u64,  // 存款数量
-    pub total_withdrawals: u64, // 取款数量
+    pub total_bucky_banks: u64, // 旧布局字段，链上不再累计
+    pub total_deposits: u64,  // 旧布局字段，链上不再累计
+    pub total_withdrawals: u64, // 旧布局字段，链上不再累计
     pub admin: Pubkey,
-}
\ No newline at end of file
+    pub paused: bool, // 紧急暂停：暂停期间拒绝所有用户指令
+    pub pending_admin: Option<Pubkey>, // 待接受的新管理员
+    pub default_min_deposit: u64, // 新建存钱罐的默认最低存款额（lamports），SPL Token 存钱罐按精度换算
+    pub default_max_deposit: u64, // 新建存钱罐的默认单笔存款上限（lamports），0 表示不限，SPL Token 存钱罐按精度换算
+}
+
+impl BankGlobalStatsInfo {
+    /// 最初上线布局中 `admin` 字段的偏移（含判别符）
+    pub const LEGACY_ADMIN_OFFSET: usize = 8 + 3 * 8;
+}
+
+/// 单条指令对全局统计的增量，随 `GlobalStatsUpdated` 事件发出，由索引器累加
+///
+/// 金额与锁定总额只统计原生 SOL。统计数据只用于展示，一律饱和运算，避免统计异常阻塞用户资金操作。
+#[derive(Default)]
+pub struct GlobalStatsDelta {
+    pub total_bucky_banks: i64,
+    pub active_bucky_banks: i64,
+    pub completed_bucky_banks: i64,
+    pub failed_bucky_banks: i64,
+    pub closed_bucky_banks: i64,
+    pub total_deposits: i64,
+    pub total_deposit_amount: i64,
+    pub total_withdrawals: i64,
+    pub total_withdrawal_amount: i64,
+    pub total_value_locked: i64,
+}
+
+fn signed(amount: u64) -> i64 {
+    i64::try_from(amount).unwrap_or(i64::MAX)
+}
+
+impl GlobalStatsDelta {
+    fn status_counter(&mut self, status: BuckyBankStatus) -> &mut i64 {
+        match status {
+            BuckyBankStatus::Active => &mut self.active_bucky_banks,
+            BuckyBankStatus::Completed => &mut self.completed_bucky_banks,
+            BuckyBankStatus::Failed => &mut self.failed_bucky_banks,
+        }
+    }
+
+    /// 记录新建存钱罐
+    pub fn record_bank_created(&mut self, status: BuckyBankStatus) {
+        self.total_bucky_banks = self.total_bucky_banks.saturating_add(1);
+        let counter = self.status_counter(status);
+        *counter = counter.saturating_add(1);
+    }
+
+    /// 记录存钱罐状态变化，状态未变时不做处理
+    pub fn record_status_change(&mut self, from: BuckyBankStatus, to: BuckyBankStatus) {
+        if from == to {
+            return;
+        }
+        let counter = self.status_counter(from);
+        *counter = counter.saturating_sub(1);
+        let counter = self.status_counter(to);
+        *counter = counter.saturating_add(1);
+    }
+
+    /// 记录存钱罐关闭，锁定总额按关闭前的记账余额扣减
+    pub fn record_bank_closed(&mut self, status: BuckyBankStatus, balance: u64, is_native: bool) {
+        let counter = self.status_counter(status);
+        *counter = counter.saturating_sub(1);
+        self.closed_bucky_banks = self.closed_bucky_banks.saturating_add(1);
+        if is_native {
+            self.total_value_locked = self.total_value_locked.saturating_sub(signed(balance));
+        }
+    }
+
+    /// 记录一笔存款
+    pub fn record_deposit(&mut self, amount: u64, is_native: bool) {
+        self.total_deposits = self.total_deposits.saturating_add(1);
+        if is_native {
+            self.total_deposit_amount = self.total_deposit_amount.saturating_add(signed(amount));
+            self.total_value_locked = self.total_value_locked.saturating_add(signed(amount));
+        }
+    }
+
+    /// 记录一笔取款
+    pub fn record_withdrawal(&mut self, amount: u64, is_native: bool) {
+        self.total_withdrawals = self.total_withdrawals.saturating_add(1);
+        if is_native {
+            self.total_withdrawal_amount = self.total_withdrawal_amount.saturating_add(signed(amount));
+            self.total_value_locked = self.total_value_locked.saturating_sub(signed(amount));
+        }
+    }
+
+    /// 记录家长配捐或利息转入存钱罐，只计入锁定总额
+    pub fn record_parent_credit(&mut self, amount: u64, is_native: bool) {
+        if is_native {
+            self.total_value_locked = self.total_value_locked.saturating_add(signed(amount));
+        }
+    }
+
+    /// 对账后按余额差值校准锁定总额
+    pub fn record_balance_sync(&mut self, previous: u64, current: u64, is_native: bool) {
+        if is_native {
+            self.total_value_locked = self
+                .total_value_locked
+                .saturating_add(signed(current).saturating_sub(signed(previous)));
+        }
+    }
+}
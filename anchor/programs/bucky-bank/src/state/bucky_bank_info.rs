@@ -9,12 +9,87 @@ pub struct Config {
     /// 存钱罐名称
     #[max_len(MAX_NAME_LENGTH)]
     pub name: String,
-    /// 目标存款金额（单位：lamports）
+    /// 目标存款金额（单位：lamports 或 Token 最小单位）
     pub target_amount: u64,
     /// 存款截止时间戳（毫秒）
     pub deadline_ms: u64,
     /// 存钱罐使用方地址
     pub child_address: Pubkey,
+    /// 存款币种，None 表示原生 SOL，否则为 SPL Token（含 Token-2022）的 Mint 地址
+    pub mint: Option<Pubkey>,
+    /// 存款币种精度
+    pub decimals: u8,
+    /// 单笔最低存款额（单位：lamports 或 Token 最小单位）
+    pub min_deposit: u64,
+    /// 单笔存款上限，0 表示不限
+    pub max_deposit: u64,
+    /// 锁定模式
+    pub lock_mode: LockMode,
+}
+
+impl Config {
+    /// 是否为原生 SOL 存钱罐
+    pub fn is_native(&self) -> bool {
+        self.mint.is_none()
+    }
+
+    /// 存款限额是否有效：最低存款额大于 0，上限为 0（不限）或不低于最低存款额
+    pub fn valid_deposit_limits(min_deposit: u64, max_deposit: u64) -> bool {
+        min_deposit > 0 && (max_deposit == 0 || max_deposit >= min_deposit)
+    }
+
+    /// 把按原生 SOL 精度设置的全局默认存款限额换算到指定精度，保持相同的整币数额
+    ///
+    /// 换算后最低存款额至少为 1；上限为 0 时仍表示不限，否则不低于最低存款额。
+    pub fn default_deposit_limits(min_deposit: u64, max_deposit: u64, decimals: u8) -> (u64, u64) {
+        let scale = |amount: u64| -> u64 {
+            let scaled = 10u128
+                .checked_pow(decimals as u32)
+                .and_then(|factor| (amount as u128).checked_mul(factor))
+                .map_or(u128::MAX, |scaled| scaled / 10u128.pow(NATIVE_SOL_DECIMALS as u32));
+            u64::try_from(scaled).unwrap_or(u64::MAX)
+        };
+
+        let min_deposit = scale(min_deposit).max(1);
+        let max_deposit = if max_deposit == 0 {
+            0
+        } else {
+            scale(max_deposit).max(min_deposit)
+        };
+        (min_deposit, max_deposit)
+    }
+}
+
+/// 存钱罐里程碑：目标之前的阶段性存款金额
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(InitSpace)]
+pub struct Milestone {
+    /// 里程碑金额（单位：lamports 或 Token 最小单位）
+    pub amount: u64,
+    /// 里程碑名称
+    #[max_len(MAX_MILESTONE_LABEL_LENGTH)]
+    pub label: String,
+    /// 达成时间（毫秒），0 表示尚未达成
+    pub reached_at_ms: u64,
+}
+
+/// 设置里程碑时传入的参数
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MilestoneInput {
+    /// 里程碑金额（单位：lamports 或 Token 最小单位）
+    pub amount: u64,
+    /// 里程碑名称
+    pub label: String,
+}
+
+/// 存钱罐锁定模式
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(InitSpace)]
+pub enum LockMode {
+    /// 随时可以申请取款
+    Flexible = 0,
+    /// 截止时间前只能申请紧急取款，需全部监护人批准，并可按家长设置收取罚金
+    TimeLocked = 1,
 }
 
 /// 存钱罐状态枚举
@@ -29,6 +104,18 @@ pub enum BuckyBankStatus {
     Failed = 2,
 }
 
+/// 存款人策略
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(InitSpace)]
+pub enum DepositorPolicy {
+    /// 仅孩子可以存款
+    ChildOnly = 0,
+    /// 孩子、家长及白名单中的地址可以存款
+    Allowlist = 1,
+    /// 任何人都可以存款
+    Anyone = 2,
+}
+
 /// 存钱罐对象
 #[account]
 #[derive(InitSpace)]
@@ -37,7 +124,7 @@ pub struct BuckyBankInfo {
     pub parent: Pubkey,
     /// 存钱罐配置
     pub config: Config,
-    /// 当前存款余额（单位：lamports）
+    /// 当前存款余额（单位：lamports 或 Token 最小单位）
     pub current_balance: u64,
     /// 存钱罐状态
     pub status: BuckyBankStatus, // 0-进行中、1-已完成、2-失败
@@ -45,8 +132,402 @@ pub struct BuckyBankInfo {
     pub deposit_count: u64,
     /// 取款请求计数器
     pub withdrawal_request_counter: u64,
+    /// 未结束的取款请求数量（待审批或已批准未提取），大于 0 时不能关闭存钱罐
+    pub open_request_count: u64,
     /// 存钱罐创建时间（毫秒）
     pub created_at_ms: u64,
     /// 最近一次存款时间（毫秒）
     pub last_deposit_ms: u64,
-}
\ No newline at end of file
+    /// 金库 PDA 的 bump：原生 SOL 存款存放于该 PDA，金库 Token 账户为其关联 Token 账户
+    pub vault_bump: u8,
+    /// 达成目标的奖励金额（家长预存于奖励金库）
+    pub reward_amount: u64,
+    /// 奖励是否已领取
+    pub reward_claimed: bool,
+    /// 奖励金库 PDA 的 bump
+    pub reward_vault_bump: u8,
+    /// 存款人策略
+    pub depositor_policy: DepositorPolicy,
+    /// 存款白名单（亲友地址），仅在 `Allowlist` 策略下生效
+    #[max_len(MAX_ALLOWED_DEPOSITORS)]
+    pub allowed_depositors: Vec<Pubkey>,
+    /// 监护人列表，取款请求由监护人投票审批
+    #[max_len(MAX_GUARDIANS)]
+    pub guardians: Vec<Pubkey>,
+    /// 批准取款所需的监护人票数（M-of-N 中的 M）
+    pub approval_threshold: u8,
+    /// 单笔自动批准上限，0 表示关闭自动批准
+    pub auto_approve_limit: u64,
+    /// 每日自动批准总额上限，0 表示不限
+    pub daily_cap: u64,
+    /// 每周自动批准总额上限，0 表示不限
+    pub weekly_cap: u64,
+    /// 当前日窗口内已自动批准的总额
+    pub daily_spent: u64,
+    /// 当前日窗口开始时间（毫秒）
+    pub daily_window_start_ms: u64,
+    /// 当前周窗口内已自动批准的总额
+    pub weekly_spent: u64,
+    /// 当前周窗口开始时间（毫秒）
+    pub weekly_window_start_ms: u64,
+    /// 待接受的新家长地址（家长角色两步转移）
+    pub pending_parent: Option<Pubkey>,
+    /// 在家长索引中所在的页号
+    pub parent_index_page: u32,
+    /// 在孩子索引中所在的页号
+    pub child_index_page: u32,
+    /// 创建时家长的存钱罐 nonce，与家长地址一起决定存钱罐地址
+    pub bank_nonce: u64,
+    /// 家长配捐比例（基点，5000 表示孩子每存 1 家长配 0.5），0 表示关闭配捐
+    pub match_ratio_bps: u16,
+    /// 每个周期的配捐总额上限，0 表示不限
+    pub match_period_cap: u64,
+    /// 配捐周期长度（毫秒）
+    pub match_period_ms: u64,
+    /// 当前配捐周期开始时间（毫秒）
+    pub match_period_start_ms: u64,
+    /// 当前周期内已配捐的总额
+    pub match_period_matched: u64,
+    /// 配捐金库中可用于配捐的余额
+    pub match_vault_balance: u64,
+    /// 配捐金库是否已开设（SPL Token 存钱罐开设后才有金库 Token 账户）
+    pub match_vault_initialized: bool,
+    /// 家长设定的年利率（基点），0 表示不计息
+    pub interest_rate_bps: u16,
+    /// 上次计息时间（毫秒）
+    pub last_interest_accrual_ms: u64,
+    /// 不足最小单位的利息，以 `INTEREST_CARRY_SCALE` 为精度结转
+    pub interest_carry: u64,
+    /// 已结算但尚未付入存钱罐的利息
+    pub interest_pending: u64,
+    /// 利息金库中可用于付息的余额
+    pub interest_vault_balance: u64,
+    /// 利息金库是否已开设
+    pub interest_vault_initialized: bool,
+    /// 累计已计入存钱罐的利息
+    pub total_interest_earned: u64,
+    /// 里程碑列表，按金额升序排列
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>,
+    /// 孩子当前的连续存款周数，上次存款所在周之后断过一周即视为中断
+    pub current_streak_weeks: u32,
+    /// 孩子最长的连续存款周数
+    pub longest_streak_weeks: u32,
+    /// 孩子最近一次存款所在的周序号（自 Unix 纪元起按 `WEEK_MS` 划分）
+    pub last_streak_week: u64,
+    /// 新取款请求的有效期（毫秒）
+    pub withdrawal_request_ttl_ms: u64,
+    /// 锁定期内紧急取款的罚金比例（基点）
+    pub emergency_penalty_bps: u16,
+    /// 罚金收款地址（如慈善地址），None 表示付给家长
+    pub penalty_recipient: Option<Pubkey>,
+    /// 家长是否已冻结存钱罐，冻结期间不能存款、申请取款或提取
+    pub frozen: bool,
+}
+
+impl BuckyBankInfo {
+    /// 推导存钱罐地址：种子为 `[BUCKY_BANK_SEED, 家长地址, nonce 小端 8 字节]`
+    pub fn derive_address(parent: &Pubkey, bank_nonce: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[BUCKY_BANK_SEED, parent.as_ref(), &bank_nonce.to_le_bytes()],
+            &crate::ID,
+        )
+    }
+
+    /// 按存款人策略判断 `depositor` 是否可以存款
+    pub fn can_deposit(&self, depositor: &Pubkey) -> bool {
+        match self.depositor_policy {
+            DepositorPolicy::ChildOnly => *depositor == self.config.child_address,
+            DepositorPolicy::Allowlist => {
+                *depositor == self.config.child_address
+                    || *depositor == self.parent
+                    || self.allowed_depositors.contains(depositor)
+            }
+            DepositorPolicy::Anyone => true,
+        }
+    }
+
+    /// 是否处于锁定期：锁定模式下截止时间之前
+    pub fn is_time_locked(&self, now_ms: u64) -> bool {
+        self.config.lock_mode == LockMode::TimeLocked && now_ms < self.config.deadline_ms
+    }
+
+    /// 是否为监护人
+    pub fn is_guardian(&self, key: &Pubkey) -> bool {
+        self.guardians.contains(key)
+    }
+
+    /// 家长转移后更新监护人列表
+    ///
+    /// 原家长总是从列表中移除，新家长不在列表中时接替原家长的位置。
+    /// 去除重复地址后，批准门槛不超过剩余的监护人数。
+    pub fn replace_guardian(&mut self, old_parent: Pubkey, new_parent: Pubkey) {
+        let Some(index) = self.guardians.iter().position(|g| *g == old_parent) else {
+            return;
+        };
+        if self.guardians.contains(&new_parent) {
+            self.guardians.remove(index);
+        } else {
+            self.guardians[index] = new_parent;
+        }
+        self.guardians.retain(|g| *g != old_parent);
+
+        let mut seen = Vec::with_capacity(self.guardians.len());
+        self.guardians.retain(|g| {
+            if seen.contains(g) {
+                false
+            } else {
+                seen.push(*g);
+                true
+            }
+        });
+
+        self.approval_threshold = self.approval_threshold.min(self.guardians.len() as u8);
+    }
+
+    /// 尝试自动批准 `amount`
+    ///
+    /// 窗口到期后重新计数。金额不超过单笔上限且计入后不超过日、周上限时，
+    /// 记入已用额度并返回 true。
+    pub fn try_auto_approve(&mut self, amount: u64, now_ms: u64) -> bool {
+        if self.auto_approve_limit == 0 || amount > self.auto_approve_limit {
+            return false;
+        }
+
+        if now_ms >= self.daily_window_start_ms.saturating_add(DAY_MS) {
+            self.daily_window_start_ms = now_ms;
+            self.daily_spent = 0;
+        }
+        if now_ms >= self.weekly_window_start_ms.saturating_add(WEEK_MS) {
+            self.weekly_window_start_ms = now_ms;
+            self.weekly_spent = 0;
+        }
+
+        let (Some(daily_spent), Some(weekly_spent)) = (
+            self.daily_spent.checked_add(amount),
+            self.weekly_spent.checked_add(amount),
+        ) else {
+            return false;
+        };
+        if (self.daily_cap > 0 && daily_spent > self.daily_cap)
+            || (self.weekly_cap > 0 && weekly_spent > self.weekly_cap)
+        {
+            return false;
+        }
+
+        self.daily_spent = daily_spent;
+        self.weekly_spent = weekly_spent;
+        true
+    }
+
+    /// 退回自动批准占用的额度
+    ///
+    /// 自动批准的请求未提取就结束时调用。只退回批准时间仍在当前窗口内的额度，窗口已重置则无需退回。
+    pub fn release_auto_approval(&mut self, amount: u64, approved_at_ms: u64) {
+        if approved_at_ms >= self.daily_window_start_ms {
+            self.daily_spent = self.daily_spent.saturating_sub(amount);
+        }
+        if approved_at_ms >= self.weekly_window_start_ms {
+            self.weekly_spent = self.weekly_spent.saturating_sub(amount);
+        }
+    }
+
+    /// 记录孩子一次存款，更新连续存款周数
+    ///
+    /// 同一周内多次存款只计一次；紧接上次存款的下一周存款时连续周数加一，否则从 1 重新计数。
+    pub fn record_streak(&mut self, now_ms: u64) {
+        let week = now_ms / WEEK_MS;
+        if self.current_streak_weeks > 0 && week == self.last_streak_week {
+            return;
+        }
+
+        self.current_streak_weeks =
+            if self.current_streak_weeks > 0 && week == self.last_streak_week.saturating_add(1) {
+                self.current_streak_weeks.saturating_add(1)
+            } else {
+                1
+            };
+        self.last_streak_week = week;
+        self.longest_streak_weeks = self.longest_streak_weeks.max(self.current_streak_weeks);
+    }
+
+    /// 将当前余额已达到的未达成里程碑记为达成，返回本次达成的里程碑序号
+    pub fn reach_milestones(&mut self, now_ms: u64) -> Vec<u8> {
+        let mut reached = Vec::new();
+        for (index, milestone) in self.milestones.iter_mut().enumerate() {
+            if milestone.reached_at_ms == 0 && self.current_balance >= milestone.amount {
+                milestone.reached_at_ms = now_ms;
+                reached.push(index as u8);
+            }
+        }
+        reached
+    }
+
+    /// 按配捐规则计算 `deposit_amount` 对应的配捐
+    ///
+    /// 周期到期后重新计数。配捐额先按周期上限截断，再以配捐金库余额为限记入，
+    /// 返回 `(应配捐额, 实际配捐额)`；两者不等说明配捐金库余额不足。
+    pub fn take_match(&mut self, deposit_amount: u64, now_ms: u64) -> (u64, u64) {
+        if self.match_ratio_bps == 0 {
+            return (0, 0);
+        }
+
+        if now_ms >= self.match_period_start_ms.saturating_add(self.match_period_ms) {
+            self.match_period_start_ms = now_ms;
+            self.match_period_matched = 0;
+        }
+
+        let mut match_amount = (deposit_amount as u128 * self.match_ratio_bps as u128
+            / BPS_DENOMINATOR as u128) as u64;
+        if self.match_period_cap > 0 {
+            match_amount = match_amount
+                .min(self.match_period_cap.saturating_sub(self.match_period_matched));
+        }
+
+        let credited = match_amount.min(self.match_vault_balance);
+        self.match_period_matched = self.match_period_matched.saturating_add(credited);
+        self.match_vault_balance -= credited;
+        (match_amount, credited)
+    }
+
+    /// 是否计息：只有进行中且未冻结的存钱罐计息
+    pub fn accrues_interest(&self) -> bool {
+        self.status == BuckyBankStatus::Active && !self.frozen
+    }
+
+    /// 按当前余额结算自上次计息以来的利息，记入应付利息但不转账
+    ///
+    /// 利息 = 余额 × 年利率 × 经过时间 / 一年，以 u128 定点运算，不足最小单位的部分
+    /// 结转到下次计息。余额、利率、状态、冻结或截止时间变化前必须先调用，保证每段时间按当时的
+    /// 条件计息。截止时间之后以及不计息期间只推进计息时间，不产生利息。
+    pub fn checkpoint_interest(&mut self, now_ms: u64) {
+        let elapsed_ms = if self.accrues_interest() {
+            now_ms
+                .min(self.config.deadline_ms)
+                .saturating_sub(self.last_interest_accrual_ms)
+        } else {
+            0
+        };
+        self.last_interest_accrual_ms = self.last_interest_accrual_ms.max(now_ms);
+        if self.interest_rate_bps == 0 || elapsed_ms == 0 {
+            return;
+        }
+
+        let denominator = BPS_DENOMINATOR as u128 * YEAR_MS as u128;
+        let numerator = (self.current_balance as u128)
+            .saturating_mul(self.interest_rate_bps as u128)
+            .saturating_mul(elapsed_ms as u128);
+        let carry = (numerator % denominator) * INTEREST_CARRY_SCALE / denominator
+            + self.interest_carry as u128;
+        let interest = u64::try_from(numerator / denominator + carry / INTEREST_CARRY_SCALE)
+            .unwrap_or(u64::MAX);
+        self.interest_carry = (carry % INTEREST_CARRY_SCALE) as u64;
+        self.interest_pending = self.interest_pending.saturating_add(interest);
+    }
+
+    /// 结算利息并以利息金库余额为限付息
+    ///
+    /// 返回 `(应付利息, 实际付息)`；两者不等说明利息金库余额不足，差额留待下次付息。
+    pub fn take_interest(&mut self, now_ms: u64) -> (u64, u64) {
+        self.checkpoint_interest(now_ms);
+
+        let interest = self.interest_pending;
+        let credited = interest.min(self.interest_vault_balance);
+        self.interest_pending -= credited;
+        self.interest_vault_balance -= credited;
+        (interest, credited)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BALANCE: u64 = 1_000_000_000;
+
+    /// 年利率 100%、截止时间为半年后的进行中存钱罐，其余字段取零值
+    fn bank() -> BuckyBankInfo {
+        let zeroed = vec![0u8; BuckyBankInfo::INIT_SPACE];
+        let mut bank = BuckyBankInfo::deserialize(&mut zeroed.as_slice()).unwrap();
+        bank.status = BuckyBankStatus::Active;
+        bank.current_balance = BALANCE;
+        bank.interest_rate_bps = BPS_DENOMINATOR as u16;
+        bank.config.deadline_ms = YEAR_MS / 2;
+        bank
+    }
+
+    #[test]
+    fn default_deposit_limits_follow_decimals() {
+        let min = DEFAULT_MIN_NATIVE_DEPOSIT;
+        let max = 100 * DEFAULT_MIN_NATIVE_DEPOSIT;
+        assert_eq!(Config::default_deposit_limits(min, max, NATIVE_SOL_DECIMALS), (min, max));
+        assert_eq!(Config::default_deposit_limits(min, max, 6), (10_000, 1_000_000));
+        assert_eq!(Config::default_deposit_limits(min, 0, 0), (1, 0));
+        assert_eq!(Config::default_deposit_limits(min, max, u8::MAX), (u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn accrues_until_deadline() {
+        let mut bank = bank();
+        bank.checkpoint_interest(YEAR_MS / 4);
+        assert_eq!(bank.interest_pending, BALANCE / 4);
+        bank.checkpoint_interest(YEAR_MS / 2);
+        assert_eq!(bank.interest_pending, BALANCE / 2);
+    }
+
+    #[test]
+    fn no_interest_after_deadline() {
+        let mut bank = bank();
+        bank.checkpoint_interest(YEAR_MS);
+        assert_eq!(bank.interest_pending, BALANCE / 2);
+        assert_eq!(bank.last_interest_accrual_ms, YEAR_MS);
+
+        bank.checkpoint_interest(2 * YEAR_MS);
+        assert_eq!(bank.interest_pending, BALANCE / 2);
+    }
+
+    #[test]
+    fn deadline_extension_does_not_cover_the_gap() {
+        let mut bank = bank();
+        bank.checkpoint_interest(YEAR_MS);
+        bank.config.deadline_ms = 2 * YEAR_MS;
+        bank.checkpoint_interest(YEAR_MS + YEAR_MS / 4);
+        assert_eq!(bank.interest_pending, BALANCE / 2 + BALANCE / 4);
+    }
+
+    #[test]
+    fn settled_banks_do_not_accrue() {
+        for status in [BuckyBankStatus::Completed, BuckyBankStatus::Failed] {
+            let mut bank = bank();
+            bank.status = status;
+            assert_eq!(bank.take_interest(YEAR_MS / 4), (0, 0));
+            assert_eq!(bank.last_interest_accrual_ms, YEAR_MS / 4);
+        }
+    }
+
+    #[test]
+    fn pending_interest_is_paid_after_settlement() {
+        for status in [BuckyBankStatus::Completed, BuckyBankStatus::Failed] {
+            let mut bank = bank();
+            bank.interest_vault_balance = BALANCE;
+            bank.checkpoint_interest(YEAR_MS / 4);
+            bank.status = status;
+
+            assert_eq!(bank.take_interest(YEAR_MS / 2), (BALANCE / 4, BALANCE / 4));
+            assert_eq!(bank.interest_pending, 0);
+            assert_eq!(bank.interest_vault_balance, BALANCE - BALANCE / 4);
+        }
+    }
+
+    #[test]
+    fn frozen_banks_do_not_accrue() {
+        let mut bank = bank();
+        bank.frozen = true;
+        bank.checkpoint_interest(YEAR_MS / 4);
+        assert_eq!(bank.interest_pending, 0);
+
+        bank.frozen = false;
+        bank.checkpoint_interest(YEAR_MS / 2);
+        assert_eq!(bank.interest_pending, BALANCE / 4);
+    }
+}
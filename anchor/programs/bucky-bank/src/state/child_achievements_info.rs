@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::STREAK_BADGE_WEEKS;
+use crate::state::{BuckyBankInfo, BuckyBankStatus};
+
+/// 成就徽章
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(InitSpace)]
+pub enum AchievementBadge {
+    /// 首次存款
+    FirstDeposit = 0,
+    /// 连续 `STREAK_BADGE_WEEKS` 周存款
+    WeeklyStreak = 1,
+    /// 存款达到目标的一半
+    HalfwayToTarget = 2,
+    /// 达成存款目标
+    GoalCompleted = 3,
+}
+
+impl AchievementBadge {
+    fn mask(self) -> u32 {
+        1 << self as u8
+    }
+}
+
+/// 孩子的成就账户，每个孩子一个，跨存钱罐累计
+#[account]
+#[derive(InitSpace)]
+pub struct ChildAchievementsInfo {
+    pub child: Pubkey,
+    pub badges: u32, // 已解锁徽章的位图，第 n 位对应 `AchievementBadge` 取值 n
+    pub longest_streak_weeks: u32, // 孩子在所有存钱罐中最长的连续存款周数
+}
+
+impl ChildAchievementsInfo {
+    /// 是否已解锁 `badge`
+    pub fn has(&self, badge: AchievementBadge) -> bool {
+        self.badges & badge.mask() != 0
+    }
+
+    /// 按存款后的存钱罐状态解锁徽章，返回本次新解锁的徽章
+    ///
+    /// 首次存款与连续存款只统计孩子本人的存款；目标进度类徽章任何人的存款都可能触发。
+    pub fn record_deposit(
+        &mut self,
+        bucky_bank: &BuckyBankInfo,
+        by_child: bool,
+    ) -> Vec<AchievementBadge> {
+        if self.child == Pubkey::default() {
+            self.child = bucky_bank.config.child_address;
+        }
+
+        let mut candidates = Vec::new();
+        if by_child {
+            self.longest_streak_weeks = self
+                .longest_streak_weeks
+                .max(bucky_bank.current_streak_weeks);
+            candidates.push(AchievementBadge::FirstDeposit);
+            if bucky_bank.current_streak_weeks >= STREAK_BADGE_WEEKS {
+                candidates.push(AchievementBadge::WeeklyStreak);
+            }
+        }
+        if bucky_bank.current_balance as u128 * 2 >= bucky_bank.config.target_amount as u128 {
+            candidates.push(AchievementBadge::HalfwayToTarget);
+        }
+        if bucky_bank.status == BuckyBankStatus::Completed {
+            candidates.push(AchievementBadge::GoalCompleted);
+        }
+
+        candidates
+            .into_iter()
+            .filter(|badge| {
+                if self.has(*badge) {
+                    return false;
+                }
+                self.badges |= badge.mask();
+                true
+            })
+            .collect()
+    }
+}
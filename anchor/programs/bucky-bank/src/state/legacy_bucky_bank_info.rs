@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::BuckyBankStatus;
+
+/// 最初上线版本的存钱罐配置
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(InitSpace)]
+pub struct LegacyConfig {
+    #[max_len(MAX_NAME_LENGTH)]
+    pub name: String,
+    pub target_amount: u64,
+    pub deadline_ms: u64,
+    pub child_address: Pubkey,
+}
+
+/// 最初上线版本的存钱罐布局
+///
+/// 该版本把存款以 lamports 直接存放在存钱罐账户上，与当前布局共用判别符，只能按账户长度区分。
+/// 只用于读取旧账户并释放其中的资金，见 `close_legacy_bucky_bank`。
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(InitSpace)]
+pub struct LegacyBuckyBankInfo {
+    pub parent: Pubkey,
+    pub config: LegacyConfig,
+    pub current_balance: u64,
+    pub status: BuckyBankStatus,
+    pub deposit_count: u64,
+    pub withdrawal_request_counter: u64,
+    pub created_at_ms: u64,
+    pub last_deposit_ms: u64,
+}
+
+impl LegacyBuckyBankInfo {
+    /// 旧存钱罐账户的数据长度（含判别符）
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR_SIZE + Self::INIT_SPACE;
+}
+
+// 旧账户只能按长度与当前布局区分，两者长度必须不同
+const _: () = assert!(
+    LegacyBuckyBankInfo::LEN != ANCHOR_DISCRIMINATOR_SIZE + crate::state::BuckyBankInfo::INIT_SPACE
+);
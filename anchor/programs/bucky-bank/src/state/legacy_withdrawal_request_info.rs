@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::WithdrawalStatus;
+
+/// 最初上线版本的取款请求布局
+///
+/// 与当前布局共用判别符，只能按账户长度区分。旧请求无法再被审批或提取，
+/// 只用于读取请求者并关闭账户，见 `close_legacy_withdrawal_request`。
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(InitSpace)]
+pub struct LegacyWithdrawalRequestInfo {
+    pub bucky_bank_id: Pubkey,
+    pub requester: Pubkey,
+    pub amount: u64,
+    #[max_len(MAX_REASON_LENGTH)]
+    pub reason: String,
+    pub status: WithdrawalStatus,
+    pub approved_by: Pubkey,
+    pub created_at_ms: u64,
+    pub approved_at_ms: u64,
+}
+
+impl LegacyWithdrawalRequestInfo {
+    /// 旧取款请求账户的数据长度（含判别符）
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR_SIZE + Self::INIT_SPACE;
+}
+
+// 旧账户只能按长度与当前布局区分，两者长度必须不同
+const _: () = assert!(
+    LegacyWithdrawalRequestInfo::LEN
+        != ANCHOR_DISCRIMINATOR_SIZE + crate::state::WithdrawalRequestInfo::INIT_SPACE
+);
@@ -1,9 +1,15 @@
 pub mod bank_global_stats_info;
 pub mod bucky_bank_info;
+pub mod child_achievements_info;
+pub mod legacy_bucky_bank_info;
+pub mod legacy_withdrawal_request_info;
 pub mod user_bucky_banks_info;
 pub mod withdrawal_request_info;
 
 pub use bank_global_stats_info::*;
 pub use bucky_bank_info::*;
+pub use child_achievements_info::*;
+pub use legacy_bucky_bank_info::*;
+pub use legacy_withdrawal_request_info::*;
 pub use user_bucky_banks_info::*;
 pub use withdrawal_request_info::*;
@@ -1,12 +1,68 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::MAX_BUCKY_BANK_ID_ARRAY_LENGTH;
+use crate::constants::BUCKY_BANK_INDEX_PAGE_SIZE;
 
+/// 用户存钱罐索引（家长索引与孩子索引共用此结构，按种子区分）
+///
+/// 存钱罐 ID 分页存放在 `UserBuckyBanksPage` 中，新存钱罐总是追加到 `current_page`，
+/// 当前页写满后切换到下一页，因此 `current_page` 指向的页一定还有空位。
 #[account]
 #[derive(InitSpace)]
 pub struct UserBuckyBanksInfo {
     pub owner: Pubkey,
+    pub total_count: u64, // 索引中的存钱罐数量
+    pub current_page: u32, // 当前写入的页号
+    pub bank_nonce: u64, // 家长已创建的存钱罐数量，作为下一个存钱罐地址的 nonce（仅家长索引使用）
+}
 
-    #[max_len(MAX_BUCKY_BANK_ID_ARRAY_LENGTH)]
+/// 用户存钱罐索引的一页
+#[account]
+#[derive(InitSpace)]
+pub struct UserBuckyBanksPage {
+    pub index: Pubkey, // 所属索引账户
+    pub page: u32,
+
+    #[max_len(BUCKY_BANK_INDEX_PAGE_SIZE)]
     pub bucky_bank_ids: Vec<Pubkey>,
 }
+
+impl UserBuckyBanksInfo {
+    /// 将存钱罐追加到当前页，返回写入的页号
+    ///
+    /// `page` 必须是 `current_page` 对应的页账户。
+    pub fn append(
+        &mut self,
+        index: Pubkey,
+        owner: Pubkey,
+        page: &mut UserBuckyBanksPage,
+        bucky_bank_id: Pubkey,
+    ) -> u32 {
+        if self.owner == Pubkey::default() {
+            self.owner = owner;
+        }
+        if page.index == Pubkey::default() {
+            page.index = index;
+            page.page = self.current_page;
+        }
+
+        let page_number = self.current_page;
+        page.bucky_bank_ids.push(bucky_bank_id);
+        self.total_count = self.total_count.saturating_add(1);
+
+        // 当前页写满后切换到下一页
+        if page.bucky_bank_ids.len() >= BUCKY_BANK_INDEX_PAGE_SIZE {
+            self.current_page = self.current_page.saturating_add(1);
+        }
+
+        page_number
+    }
+
+    /// 从存钱罐所在页中移除
+    pub fn remove(&mut self, page: &mut UserBuckyBanksPage, bucky_bank_id: Pubkey) {
+        let before = page.bucky_bank_ids.len();
+        page.bucky_bank_ids.retain(|id| *id != bucky_bank_id);
+        if page.bucky_bank_ids.len() < before {
+            self.total_count = self.total_count.saturating_sub(1);
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::constants::*;
+use crate::error_code::BuckyBankError;
 
 /// 取款请求状态枚举
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 #[derive(InitSpace)]
 pub enum WithdrawalStatus {
     /// 待审批
@@ -14,6 +15,72 @@ pub enum WithdrawalStatus {
     Rejected = 2,
     /// 已完成
     Completed = 3,
+    /// 已取消（孩子撤回，账户随即关闭）
+    Cancelled = 4,
+    /// 已过期（超过有效期仍未审批，账户随即关闭）
+    Expired = 5,
+}
+
+impl WithdrawalStatus {
+    /// 状态机：校验从当前状态到 `to` 的迁移是否合法
+    ///
+    /// 合法迁移只有六条：
+    /// - Pending  -> Approved（家长批准）
+    /// - Pending  -> Rejected（家长拒绝）
+    /// - Approved -> Completed（孩子提取）
+    /// - Pending  -> Cancelled（孩子撤回）
+    /// - Approved -> Cancelled（孩子撤回，尚未提取）
+    /// - Pending  -> Expired（超过有效期仍未审批）
+    ///
+    /// 其余每一种迁移都返回各自专属的错误码。
+    pub fn transition(self, to: WithdrawalStatus) -> std::result::Result<WithdrawalStatus, BuckyBankError> {
+        use WithdrawalStatus::*;
+
+        match (self, to) {
+            (Pending, Approved)
+            | (Pending, Rejected)
+            | (Approved, Completed)
+            | (Pending, Cancelled)
+            | (Approved, Cancelled)
+            | (Pending, Expired) => Ok(to),
+
+            (Pending, Pending) => Err(BuckyBankError::RequestAlreadyPending),
+            (Pending, Completed) => Err(BuckyBankError::RequestNotApproved),
+
+            (Approved, Pending) => Err(BuckyBankError::ApprovedRequestCannotRevert),
+            (Approved, Approved) => Err(BuckyBankError::RequestAlreadyApproved),
+            (Approved, Rejected) => Err(BuckyBankError::ApprovedRequestCannotBeRejected),
+            (Approved, Expired) => Err(BuckyBankError::ApprovedRequestCannotExpire),
+
+            (Rejected, Pending) => Err(BuckyBankError::RejectedRequestCannotReopen),
+            (Rejected, Approved) => Err(BuckyBankError::RejectedRequestCannotBeApproved),
+            (Rejected, Rejected) => Err(BuckyBankError::RequestAlreadyRejected),
+            (Rejected, Completed) => Err(BuckyBankError::RejectedRequestCannotBeWithdrawn),
+            (Rejected, Cancelled) => Err(BuckyBankError::RejectedRequestCannotBeCancelled),
+            (Rejected, Expired) => Err(BuckyBankError::RejectedRequestCannotExpire),
+
+            (Completed, Pending) => Err(BuckyBankError::CompletedRequestCannotReopen),
+            (Completed, Approved) => Err(BuckyBankError::CompletedRequestCannotBeApproved),
+            (Completed, Rejected) => Err(BuckyBankError::CompletedRequestCannotBeRejected),
+            (Completed, Completed) => Err(BuckyBankError::RequestAlreadyCompleted),
+            (Completed, Cancelled) => Err(BuckyBankError::CompletedRequestCannotBeCancelled),
+            (Completed, Expired) => Err(BuckyBankError::CompletedRequestCannotExpire),
+
+            (Cancelled, Pending) => Err(BuckyBankError::CancelledRequestCannotReopen),
+            (Cancelled, Approved) => Err(BuckyBankError::CancelledRequestCannotBeApproved),
+            (Cancelled, Rejected) => Err(BuckyBankError::CancelledRequestCannotBeRejected),
+            (Cancelled, Completed) => Err(BuckyBankError::CancelledRequestCannotBeWithdrawn),
+            (Cancelled, Cancelled) => Err(BuckyBankError::RequestAlreadyCancelled),
+            (Cancelled, Expired) => Err(BuckyBankError::CancelledRequestCannotExpire),
+
+            (Expired, Pending) => Err(BuckyBankError::ExpiredRequestCannotReopen),
+            (Expired, Approved) => Err(BuckyBankError::ExpiredRequestCannotBeApproved),
+            (Expired, Rejected) => Err(BuckyBankError::ExpiredRequestCannotBeRejected),
+            (Expired, Completed) => Err(BuckyBankError::ExpiredRequestCannotBeWithdrawn),
+            (Expired, Cancelled) => Err(BuckyBankError::ExpiredRequestCannotBeCancelled),
+            (Expired, Expired) => Err(BuckyBankError::RequestAlreadyExpired),
+        }
+    }
 }
 
 /// 取款请求对象
@@ -24,17 +91,209 @@ pub struct WithdrawalRequestInfo {
     pub bucky_bank_id: Pubkey,
     /// 请求者地址（孩子）
     pub requester: Pubkey,
-    /// 取款金额（单位：lamports）
-    pub amount: u64,
+    /// 孩子请求的取款金额（单位：lamports 或 Token 最小单位）
+    pub requested_amount: u64,
+    /// 批准提取的金额，可小于请求金额；审批中为批准票中最小的金额，批准前为 0
+    pub approved_amount: u64,
     /// 取款原因
     #[max_len(MAX_REASON_LENGTH)]
     pub reason: String,
     /// 取款请求状态
     pub status: WithdrawalStatus,
-    /// 审批者地址（家长）
+    /// 审批者地址（家长），审批前为默认地址
     pub approved_by: Pubkey,
     /// 创建时间（毫秒）
     pub created_at_ms: u64,
     /// 审批时间（毫秒）
     pub approved_at_ms: u64,
+    /// 提取完成时间（毫秒）
+    pub completed_at_ms: u64,
+    /// 投票批准的监护人
+    #[max_len(MAX_GUARDIANS)]
+    pub approvals: Vec<Pubkey>,
+    /// 投票拒绝的监护人
+    #[max_len(MAX_GUARDIANS)]
+    pub rejections: Vec<Pubkey>,
+    /// 是否按自动批准规则直接批准
+    pub auto_approved: bool,
+    /// 过期时间（毫秒），到期仍未审批的请求不能再投票，任何人都可以将其关闭
+    pub expires_at_ms: u64,
+    /// 是否为锁定期内的紧急取款请求，需全部监护人批准
+    pub emergency: bool,
+    /// 紧急取款的罚金比例（基点），创建请求时按存钱罐设置确定
+    pub penalty_bps: u16,
+    /// 罚金收款地址，非紧急请求为默认地址
+    pub penalty_recipient: Pubkey,
+}
+
+impl WithdrawalRequestInfo {
+    /// 该监护人是否已投票
+    pub fn has_voted(&self, guardian: &Pubkey) -> bool {
+        self.approvals.contains(guardian) || self.rejections.contains(guardian)
+    }
+
+    /// 提取时从批准金额中扣除的罚金
+    pub fn penalty_amount(&self) -> u64 {
+        (self.approved_amount as u128 * self.penalty_bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+
+    /// 批准取款所需的票数：紧急请求需要全部监护人批准
+    pub fn required_approvals(&self, guardian_count: usize, approval_threshold: u8) -> usize {
+        if self.emergency {
+            guardian_count
+        } else {
+            approval_threshold as usize
+        }
+    }
+
+    /// 待审批请求是否已过期
+    pub fn is_expired(&self, now_ms: u64) -> bool {
+        self.status == WithdrawalStatus::Pending && now_ms >= self.expires_at_ms
+    }
+
+    /// 按状态机迁移到 `to`，非法迁移返回对应错误
+    pub fn transition_to(&mut self, to: WithdrawalStatus) -> Result<()> {
+        self.status = self.status.transition(to)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WithdrawalStatus::{self, *};
+    use crate::error_code::BuckyBankError;
+
+    const ALL: [WithdrawalStatus; 6] = [Pending, Approved, Rejected, Completed, Cancelled, Expired];
+
+    /// 业务规则允许的全部迁移，独立于实现手写
+    const LEGAL: [(WithdrawalStatus, WithdrawalStatus); 6] = [
+        (Pending, Approved),
+        (Pending, Rejected),
+        (Pending, Cancelled),
+        (Pending, Expired),
+        (Approved, Completed),
+        (Approved, Cancelled),
+    ];
+
+    fn assert_rejected(from: WithdrawalStatus, to: WithdrawalStatus, error: BuckyBankError) {
+        assert_eq!(
+            from.transition(to).map_err(|e| e as u32),
+            Err(error as u32),
+            "{:?} -> {:?}",
+            from,
+            to
+        );
+    }
+
+    #[test]
+    fn legal_transitions_succeed() {
+        for (from, to) in LEGAL {
+            assert_eq!(from.transition(to).ok(), Some(to), "{:?} -> {:?}", from, to);
+        }
+    }
+
+    #[test]
+    fn every_other_pair_is_rejected() {
+        for from in ALL {
+            for to in ALL {
+                if !LEGAL.contains(&(from, to)) {
+                    assert!(from.transition(to).is_err(), "{:?} -> {:?}", from, to);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn pending_rejections() {
+        assert_rejected(Pending, Pending, BuckyBankError::RequestAlreadyPending);
+        assert_rejected(Pending, Completed, BuckyBankError::RequestNotApproved);
+    }
+
+    #[test]
+    fn approved_rejections() {
+        assert_rejected(Approved, Pending, BuckyBankError::ApprovedRequestCannotRevert);
+        assert_rejected(Approved, Approved, BuckyBankError::RequestAlreadyApproved);
+        assert_rejected(Approved, Rejected, BuckyBankError::ApprovedRequestCannotBeRejected);
+        assert_rejected(Approved, Expired, BuckyBankError::ApprovedRequestCannotExpire);
+    }
+
+    #[test]
+    fn rejected_rejections() {
+        assert_rejected(Rejected, Pending, BuckyBankError::RejectedRequestCannotReopen);
+        assert_rejected(Rejected, Approved, BuckyBankError::RejectedRequestCannotBeApproved);
+        assert_rejected(Rejected, Rejected, BuckyBankError::RequestAlreadyRejected);
+        assert_rejected(Rejected, Completed, BuckyBankError::RejectedRequestCannotBeWithdrawn);
+        assert_rejected(Rejected, Cancelled, BuckyBankError::RejectedRequestCannotBeCancelled);
+        assert_rejected(Rejected, Expired, BuckyBankError::RejectedRequestCannotExpire);
+    }
+
+    #[test]
+    fn completed_rejections() {
+        assert_rejected(Completed, Pending, BuckyBankError::CompletedRequestCannotReopen);
+        assert_rejected(Completed, Approved, BuckyBankError::CompletedRequestCannotBeApproved);
+        assert_rejected(Completed, Rejected, BuckyBankError::CompletedRequestCannotBeRejected);
+        assert_rejected(Completed, Completed, BuckyBankError::RequestAlreadyCompleted);
+        assert_rejected(Completed, Cancelled, BuckyBankError::CompletedRequestCannotBeCancelled);
+        assert_rejected(Completed, Expired, BuckyBankError::CompletedRequestCannotExpire);
+    }
+
+    #[test]
+    fn cancelled_rejections() {
+        assert_rejected(Cancelled, Pending, BuckyBankError::CancelledRequestCannotReopen);
+        assert_rejected(Cancelled, Approved, BuckyBankError::CancelledRequestCannotBeApproved);
+        assert_rejected(Cancelled, Rejected, BuckyBankError::CancelledRequestCannotBeRejected);
+        assert_rejected(Cancelled, Completed, BuckyBankError::CancelledRequestCannotBeWithdrawn);
+        assert_rejected(Cancelled, Cancelled, BuckyBankError::RequestAlreadyCancelled);
+        assert_rejected(Cancelled, Expired, BuckyBankError::CancelledRequestCannotExpire);
+    }
+
+    #[test]
+    fn expired_rejections() {
+        assert_rejected(Expired, Pending, BuckyBankError::ExpiredRequestCannotReopen);
+        assert_rejected(Expired, Approved, BuckyBankError::ExpiredRequestCannotBeApproved);
+        assert_rejected(Expired, Rejected, BuckyBankError::ExpiredRequestCannotBeRejected);
+        assert_rejected(Expired, Completed, BuckyBankError::ExpiredRequestCannotBeWithdrawn);
+        assert_rejected(Expired, Cancelled, BuckyBankError::ExpiredRequestCannotBeCancelled);
+        assert_rejected(Expired, Expired, BuckyBankError::RequestAlreadyExpired);
+    }
+
+    #[test]
+    fn illegal_transitions_have_distinct_errors() {
+        let mut codes: Vec<u32> = ALL
+            .iter()
+            .flat_map(|&from| ALL.iter().map(move |&to| from.transition(to)))
+            .filter_map(|r| r.err().map(|e| e as u32))
+            .collect();
+        let total = codes.len();
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(total, ALL.len() * ALL.len() - LEGAL.len());
+        assert_eq!(codes.len(), total);
+    }
+
+    #[test]
+    fn only_approved_can_be_withdrawn() {
+        for from in ALL {
+            assert_eq!(from.transition(Completed).is_ok(), from == Approved, "{:?}", from);
+        }
+    }
+
+    #[test]
+    fn only_open_requests_can_be_cancelled() {
+        for from in ALL {
+            assert_eq!(
+                from.transition(Cancelled).is_ok(),
+                matches!(from, Pending | Approved),
+                "{:?}",
+                from
+            );
+        }
+    }
+
+    #[test]
+    fn only_pending_requests_can_expire() {
+        for from in ALL {
+            assert_eq!(from.transition(Expired).is_ok(), from == Pending, "{:?}", from);
+        }
+    }
 }
@@ -0,0 +1,399 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::error_code::BuckyBankError;
+use crate::events::GlobalStatsUpdated;
+use crate::state::GlobalStatsDelta;
+
+/// 从用户转入 lamports 到金库 PDA
+pub fn transfer_lamports_to_vault<'info>(
+    from: &Signer<'info>,
+    vault: &SystemAccount<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: from.to_account_info(),
+                to: vault.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+/// 为金库 PDA 补足免租金最低余额，该部分不计入任何余额
+pub fn fund_rent_exempt_minimum<'info>(
+    payer: &Signer<'info>,
+    vault: &SystemAccount<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let vault_lamports = vault.lamports();
+    if vault_lamports < rent_exempt_minimum {
+        transfer_lamports_to_vault(
+            payer,
+            vault,
+            system_program,
+            rent_exempt_minimum - vault_lamports,
+        )?;
+    }
+    Ok(())
+}
+
+/// 由金库 PDA（种子为 `[vault_seed, bucky_bank]`）签名，从金库转出 lamports
+pub fn transfer_lamports_from_vault<'info>(
+    vault_seed: &[u8],
+    bucky_bank: &Pubkey,
+    vault_bump: u8,
+    vault: &SystemAccount<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[vault_seed, bucky_bank.as_ref(), &[vault_bump]]];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: to.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+/// 从用户 Token 账户转入金库 Token 账户，返回金库实际到账数量
+///
+/// Token-2022 的转账手续费扩展会使到账数量小于转账数量，因此以金库余额差值为准。
+pub fn transfer_tokens_to_vault<'info>(
+    authority: &Signer<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    vault_token_account: &mut InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<u64> {
+    let before = vault_token_account.amount;
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: vault_token_account.to_account_info(),
+                authority: authority.to_account_info(),
+            },
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    vault_token_account.reload()?;
+    Ok(vault_token_account.amount.saturating_sub(before))
+}
+
+/// 由金库 PDA（种子为 `[vault_seed, bucky_bank]`）签名，从金库 Token 账户转出
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_tokens_from_vault<'info>(
+    vault_seed: &[u8],
+    bucky_bank: &Pubkey,
+    vault_bump: u8,
+    vault: &SystemAccount<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    vault_token_account: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[vault_seed, bucky_bank.as_ref(), &[vault_bump]]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: vault_token_account.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// 从付款人转入指定金库，原生 SOL 存钱罐转 lamports，SPL Token 存钱罐转入金库 Token 账户
+///
+/// 返回金库实际到账数量。
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_into_vault<'info>(
+    bank_mint: Option<Pubkey>,
+    payer: &Signer<'info>,
+    vault: &SystemAccount<'info>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    payer_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    vault_token_account: Option<&mut InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<u64> {
+    match bank_mint {
+        None => {
+            transfer_lamports_to_vault(payer, vault, system_program, amount)?;
+            Ok(amount)
+        }
+        Some(bank_mint) => {
+            let (Some(mint), Some(from), Some(vault_token_account), Some(token_program)) =
+                (mint, payer_token_account, vault_token_account, token_program)
+            else {
+                return err!(BuckyBankError::MissingTokenAccounts);
+            };
+            require_keys_eq!(mint.key(), bank_mint, BuckyBankError::InvalidMint);
+
+            transfer_tokens_to_vault(payer, mint, from, vault_token_account, token_program, amount)
+        }
+    }
+}
+
+/// 由金库 PDA（种子为 `[vault_seed, bucky_bank]`）签名，从金库向收款人付款
+#[allow(clippy::too_many_arguments)]
+pub fn payout_from_vault<'info>(
+    vault_seed: &[u8],
+    bucky_bank: &Pubkey,
+    vault_bump: u8,
+    bank_mint: Option<Pubkey>,
+    vault: &SystemAccount<'info>,
+    recipient: &AccountInfo<'info>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    vault_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    recipient_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    match bank_mint {
+        None => transfer_lamports_from_vault(
+            vault_seed,
+            bucky_bank,
+            vault_bump,
+            vault,
+            recipient,
+            system_program,
+            amount,
+        ),
+        Some(bank_mint) => {
+            let (Some(mint), Some(vault_token_account), Some(to), Some(token_program)) =
+                (mint, vault_token_account, recipient_token_account, token_program)
+            else {
+                return err!(BuckyBankError::MissingTokenAccounts);
+            };
+            require_keys_eq!(mint.key(), bank_mint, BuckyBankError::InvalidMint);
+
+            transfer_tokens_from_vault(
+                vault_seed,
+                bucky_bank,
+                vault_bump,
+                vault,
+                mint,
+                vault_token_account,
+                to,
+                token_program,
+                amount,
+            )
+        }
+    }
+}
+
+/// 由来源金库 PDA（种子为 `[source_seed, bucky_bank]`）签名，向存钱罐金库转入
+///
+/// 用于家长配捐、利息等转入存钱罐的资金，返回存钱罐金库实际到账数量。
+#[allow(clippy::too_many_arguments)]
+pub fn credit_bank_vault<'info>(
+    source_seed: &[u8],
+    bucky_bank: &Pubkey,
+    source_bump: u8,
+    bank_mint: Option<Pubkey>,
+    source_vault: &SystemAccount<'info>,
+    vault: &SystemAccount<'info>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    source_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    vault_token_account: Option<&mut InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<u64> {
+    let Some(vault_token_account) = vault_token_account else {
+        payout_from_vault(
+            source_seed,
+            bucky_bank,
+            source_bump,
+            bank_mint,
+            source_vault,
+            &vault.to_account_info(),
+            mint,
+            source_token_account,
+            None,
+            token_program,
+            system_program,
+            amount,
+        )?;
+        return Ok(amount);
+    };
+
+    // Token-2022 的转账手续费扩展会使到账数量小于转账数量，因此以金库余额差值为准
+    let before = vault_token_account.amount;
+    payout_from_vault(
+        source_seed,
+        bucky_bank,
+        source_bump,
+        bank_mint,
+        source_vault,
+        &vault.to_account_info(),
+        mint,
+        source_token_account,
+        Some(vault_token_account),
+        token_program,
+        system_program,
+        amount,
+    )?;
+    vault_token_account.reload()?;
+    Ok(vault_token_account.amount.saturating_sub(before))
+}
+
+/// 清空并注销金库：全部余额付给收款人，免租金部分及 Token 账户租金退还给 `rent_recipient`
+///
+/// 返回付给收款人的数量。
+#[allow(clippy::too_many_arguments)]
+pub fn sweep_vault<'info>(
+    vault_seed: &[u8],
+    bucky_bank: &Pubkey,
+    vault_bump: u8,
+    bank_mint: Option<Pubkey>,
+    vault: &SystemAccount<'info>,
+    recipient: &AccountInfo<'info>,
+    rent_recipient: &AccountInfo<'info>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    vault_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    recipient_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_program: Option<&Interface<'info, TokenInterface>>,
+    system_program: &Program<'info, System>,
+) -> Result<u64> {
+    let payout_amount = match bank_mint {
+        None => {
+            let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+            let amount = vault.lamports().saturating_sub(rent_exempt_minimum);
+            if amount > 0 {
+                transfer_lamports_from_vault(
+                    vault_seed,
+                    bucky_bank,
+                    vault_bump,
+                    vault,
+                    recipient,
+                    system_program,
+                    amount,
+                )?;
+            }
+            amount
+        }
+        Some(bank_mint) => {
+            let (Some(mint), Some(vault_token_account), Some(to), Some(token_program)) =
+                (mint, vault_token_account, recipient_token_account, token_program)
+            else {
+                return err!(BuckyBankError::MissingTokenAccounts);
+            };
+            require_keys_eq!(mint.key(), bank_mint, BuckyBankError::InvalidMint);
+            require_keys_eq!(to.owner, recipient.key(), BuckyBankError::InvalidRecipient);
+
+            let amount = vault_token_account.amount;
+            if amount > 0 {
+                transfer_tokens_from_vault(
+                    vault_seed,
+                    bucky_bank,
+                    vault_bump,
+                    vault,
+                    mint,
+                    vault_token_account,
+                    to,
+                    token_program,
+                    amount,
+                )?;
+            }
+
+            let signer_seeds: &[&[&[u8]]] = &[&[vault_seed, bucky_bank.as_ref(), &[vault_bump]]];
+            token_interface::close_account(CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: vault_token_account.to_account_info(),
+                    destination: rent_recipient.clone(),
+                    authority: vault.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+            amount
+        }
+    };
+
+    // 剩余的免租金部分退还，金库余额归零后由运行时回收
+    let remaining = vault.lamports();
+    if remaining > 0 {
+        transfer_lamports_from_vault(
+            vault_seed,
+            bucky_bank,
+            vault_bump,
+            vault,
+            rent_recipient,
+            system_program,
+            remaining,
+        )?;
+    }
+
+    Ok(payout_amount)
+}
+
+/// 发出全局统计增量
+pub fn emit_global_stats(delta: GlobalStatsDelta, bucky_bank_id: Pubkey, updated_at_ms: u64) {
+    emit!(GlobalStatsUpdated {
+        bucky_bank_id,
+        total_bucky_banks: delta.total_bucky_banks,
+        active_bucky_banks: delta.active_bucky_banks,
+        completed_bucky_banks: delta.completed_bucky_banks,
+        failed_bucky_banks: delta.failed_bucky_banks,
+        closed_bucky_banks: delta.closed_bucky_banks,
+        total_deposits: delta.total_deposits,
+        total_deposit_amount: delta.total_deposit_amount,
+        total_withdrawals: delta.total_withdrawals,
+        total_withdrawal_amount: delta.total_withdrawal_amount,
+        total_value_locked: delta.total_value_locked,
+        updated_at_ms,
+    });
+}
+
+/// 关闭本程序拥有的账户：lamports 全部转给 `destination`，清空数据并归还系统程序
+///
+/// 用于无法按当前结构反序列化、不能使用 `close` 约束的旧布局账户。
+pub fn close_program_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(BuckyBankError::Overflow)?;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&system_program::ID);
+    account.realloc(0, false)?;
+    Ok(())
+}
@@ -79,7 +79,8 @@ export function WithdrawalRequestsList({
             'Approved': 'bg-green-100 text-green-800',
             'Rejected': 'bg-red-100 text-red-800',
             'Cancelled': 'bg-gray-100 text-gray-800',
-            'Withdrawed': 'bg-blue-100 text-blue-800'
+            'Withdrawed': 'bg-blue-100 text-blue-800',
+            'Expired': 'bg-gray-100 text-gray-500'
         };
         return styles[status as keyof typeof styles] || 'bg-gray-100 text-gray-800';
     };
@@ -91,7 +92,8 @@ export function WithdrawalRequestsList({
             'Approved': '已批准',
             'Rejected': '已拒绝',
             'Cancelled': '已取消',
-            'Withdrawed': '已提取'
+            'Withdrawed': '已提取',
+            'Expired': '已过期'
         };
         return statusMap[status as keyof typeof statusMap] || status;
     };
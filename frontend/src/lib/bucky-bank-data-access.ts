@@ -1,12 +1,34 @@
 import { useQuery } from "@tanstack/react-query";
 import { Cluster, PublicKey } from "@solana/web3.js";
 import { useMemo } from "react";
+import { BN } from "@coral-xyz/anchor";
 import { useCluster } from "@/providers/ClusterProvider";
 import { useAnchorProvider } from "@/providers/SolanaProvider";
 import { getBuckyBankProgram, getBuckyBankProgramId } from "@/lib/anchor-exports";
 
 const BANK_GLOBAL_STATS_SEED = "global_stats";
 const USER_BUCKY_BANKS_SEED = "user_bucky_banks";
+const BUCKY_BANK_SEED = "bucky_bank";
+
+/**
+ * 推导存钱罐地址，与合约 `BuckyBankInfo::derive_address` 一致：
+ * 种子为 ["bucky_bank", 家长地址, nonce 小端 8 字节]
+ */
+export function deriveBuckyBankAddress(
+    programId: PublicKey,
+    parent: PublicKey,
+    bankNonce: BN | number
+): PublicKey {
+    const [address] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(BUCKY_BANK_SEED),
+            parent.toBuffer(),
+            new BN(bankNonce).toArrayLike(Buffer, "le", 8),
+        ],
+        programId
+    );
+    return address;
+}
 
 export function useBuckyBankProgram() {
     const { cluster } = useCluster();
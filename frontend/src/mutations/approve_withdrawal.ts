@@ -2,6 +2,7 @@
 
 import { useMutation, useQueryClient } from "@tanstack/react-query";
 import { PublicKey, Transaction } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
 import { toast } from "sonner";
 import { useCluster } from "@/providers/ClusterProvider";
 import { useAnchorProvider } from "@/providers/SolanaProvider";
@@ -12,6 +13,8 @@ export interface ApproveWithdrawalParams {
     buckyBankId: string;
     approve: boolean;
     reason: string;
+    /** 批准金额（还价），不传表示批准全部请求金额 */
+    approvedAmount?: number;
 }
 
 /**
@@ -31,6 +34,7 @@ export function useApproveWithdrawal() {
             buckyBankId,
             approve,
             reason,
+            approvedAmount,
         }: ApproveWithdrawalParams) => {
             if (!provider.publicKey) {
                 throw new Error("You need to connect your wallet first!");
@@ -59,11 +63,15 @@ export function useApproveWithdrawal() {
 
             // 创建审批指令
             const approveWithdrawalIx = await program.methods
-                .approveWithdrawal(approve, reason)
+                .approveWithdrawal(
+                    approve,
+                    reason,
+                    approve && approvedAmount !== undefined ? new BN(approvedAmount) : null
+                )
                 .accountsStrict({
                     buckyBank: buckyBankPublicKey,
                     withdrawalRequest: withdrawalRequestPublicKey,
-                    parent: provider.publicKey,
+                    guardian: provider.publicKey,
                 })
                 .instruction();
 
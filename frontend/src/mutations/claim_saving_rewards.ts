@@ -1,40 +1,59 @@
-import { useMutation } from "@tanstack/react-query";
-import { useWallet } from "@solana/wallet-adapter-react";
-import { useConnection } from "@solana/wallet-adapter-react";
-import { PublicKey, Transaction } from "@solana/web3.js";
+"use client";
+
+import { useMutation, useQueryClient } from "@tanstack/react-query";
+import { PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
 import { toast } from "sonner";
+import { useCluster } from "@/providers/ClusterProvider";
+import { useAnchorProvider } from "@/providers/SolanaProvider";
+import { useBuckyBankProgram } from "@/lib/bucky-bank-data-access";
 
 export interface ClaimParams {
     buckyBankId: string;
 }
 
+/**
+ * 领取达成目标奖励 Hook
+ * 调用智能合约的 claim_saving_rewards 函数，存钱罐已完成时由孩子领取奖励
+ */
 export function useClaimSavingRewards({ buckyBankId }: ClaimParams) {
-    const { publicKey, sendTransaction } = useWallet();
-    const { connection } = useConnection();
+    const { cluster } = useCluster();
+    const provider = useAnchorProvider();
+    const { program } = useBuckyBankProgram();
+    const queryClient = useQueryClient();
 
     return useMutation({
+        mutationKey: ["bucky-bank", "claim_saving_rewards", { cluster, buckyBankId }],
         mutationFn: async () => {
-            if (!publicKey) {
+            if (!provider.publicKey) {
                 throw new Error("You need to connect your wallet first!");
             }
 
-            const tx = new Transaction();
-
             console.log("Claiming saving rewards...");
-            // 这里需要根据你的 Solana 合约来调整
-            // 示例：调用合约的 claim_reward 函数
+            console.log(`Bucky Bank ID: ${buckyBankId}`);
+            console.log(`Child: ${provider.publicKey}`);
 
-            // 发送交易
-            const signature = await sendTransaction(tx, connection);
+            // 奖励金库 PDA 由 IDL 中的种子自动推导
+            const claimIx = await program.methods
+                .claimSavingRewards()
+                .accountsPartial({
+                    buckyBank: new PublicKey(buckyBankId),
+                    child: provider.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .instruction();
 
-            // 等待确认
-            await connection.confirmTransaction(signature, "confirmed");
+            const transaction = new Transaction();
+            transaction.add(claimIx);
 
-            return signature;
+            return await provider.sendAndConfirm(transaction);
         },
         onError: (error) => {
             console.error("Failed to claim rewards: ", error);
-            toast.error(`领取奖励失败: ${error.message}`);
+            toast.error(
+                `领取奖励失败: ${
+                    error instanceof Error ? error.message : "未知错误"
+                }`
+            );
         },
         onSuccess: (signature) => {
             console.log("Successfully claimed rewards: ", signature);
@@ -49,6 +68,10 @@ export function useClaimSavingRewards({ buckyBankId }: ClaimParams) {
                     },
                 },
             });
+
+            queryClient.invalidateQueries({
+                queryKey: ["bucky-bank", "bucky_banks"],
+            });
         },
     });
 }
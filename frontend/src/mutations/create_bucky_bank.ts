@@ -6,10 +6,37 @@ import { toast } from "sonner";
 import { useCluster } from "@/providers/ClusterProvider";
 import { useAnchorProvider } from "@/providers/SolanaProvider";
 import { BN } from "@coral-xyz/anchor";
-import { useBuckyBankProgram } from "@/lib/bucky-bank-data-access";
+import { deriveBuckyBankAddress, useBuckyBankProgram } from "@/lib/bucky-bank-data-access";
 import { Transaction } from "@solana/web3.js";
 import type { BuckyBankInfo } from "@/types";
 
+/**
+ * 获取用户存钱罐索引及其当前写入页的 PDA
+ */
+async function findBankIndexPDAs(
+    program: ReturnType<typeof useBuckyBankProgram>["program"],
+    programId: PublicKey,
+    seed: string,
+    owner: PublicKey
+) {
+    const [index] = PublicKey.findProgramAddressSync(
+        [Buffer.from(seed), owner.toBuffer()],
+        programId
+    );
+    const indexAccount = await program.account.userBuckyBanksInfo.fetchNullable(index);
+    const currentPage = indexAccount ? indexAccount.currentPage : 0;
+    const bankNonce = indexAccount ? indexAccount.bankNonce : new BN(0);
+    const [page] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("bucky_bank_index_page"),
+            index.toBuffer(),
+            new BN(currentPage).toArrayLike(Buffer, "le", 4),
+        ],
+        programId
+    );
+    return { index, page, bankNonce };
+}
+
 export function useCreateBuckyBank() {
     const { cluster } = useCluster();
     const provider = useAnchorProvider();
@@ -26,25 +53,32 @@ export function useCreateBuckyBank() {
                 throw new Error("Bank global stats not found");
             }
 
-            // 1. 获取当前存钱罐总数
-            const bankGlobalStatsAccount =
-                await program.account.bankGlobalStatsInfo.fetch(bankGlobalStats);
-            const buckyBankId = bankGlobalStatsAccount.totalBuckyBanks;
-
-            console.log(`Creating bucky bank with ID: ${buckyBankId.toString()}`);
+            const childAddress = new PublicKey(info.child_address);
 
-            // 2. 获取 BuckyBank PDA
-            const [buckyBankPDA] = PublicKey.findProgramAddressSync(
-                [buckyBankId.toArrayLike(Buffer, "le", 8)],
-                programId
+            // 1. 获取家长索引和孩子索引的 PDA
+            const parentIndex = await findBankIndexPDAs(
+                program,
+                programId,
+                "user_bucky_banks",
+                provider.publicKey
             );
+            const childIndex = await findBankIndexPDAs(
+                program,
+                programId,
+                "child_bucky_banks",
+                childAddress
+            );
+            const userBuckyBanksPDA = parentIndex.index;
 
-            // 3. 获取 UserBuckyBanks PDA
-            const [userBuckyBanksPDA] = PublicKey.findProgramAddressSync(
-                [Buffer.from("user_bucky_banks"), provider.publicKey.toBuffer()],
-                programId
+            // 2. 由家长地址和家长的存钱罐 nonce 推导 BuckyBank PDA
+            const buckyBankPDA = deriveBuckyBankAddress(
+                programId,
+                provider.publicKey,
+                parentIndex.bankNonce
             );
 
+            console.log(`Creating bucky bank with nonce: ${parentIndex.bankNonce.toString()}`);
+
             console.log(`Owner: ${provider.publicKey}`);
             console.log(`Bank Global Stats: ${bankGlobalStats.toBase58()}`);
             console.log(`Bucky Bank PDA: ${buckyBankPDA.toBase58()}`);
@@ -52,7 +86,6 @@ export function useCreateBuckyBank() {
 
             const targetAmountBN = new BN(info.target_amount);
             const durationDaysBN = new BN(info.duration_days);
-            const childAddress = new PublicKey(info.child_address);
 
             // 创建创建存钱罐的指令
             const createBuckyBankIx = await program.methods
@@ -67,6 +100,9 @@ export function useCreateBuckyBank() {
                     bankGlobalStats,
                     buckyBank: buckyBankPDA,
                     userBuckyBanks: userBuckyBanksPDA,
+                    userBuckyBanksPage: parentIndex.page,
+                    childBuckyBanks: childIndex.index,
+                    childBuckyBanksPage: childIndex.page,
                     systemProgram: SystemProgram.programId,
                 })
                 .instruction();
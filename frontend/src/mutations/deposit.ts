@@ -1,7 +1,7 @@
 "use client";
 
 import { useMutation } from "@tanstack/react-query";
-import { LAMPORTS_PER_SOL, SystemProgram, Transaction } from "@solana/web3.js";
+import { LAMPORTS_PER_SOL, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
 import { toast } from "sonner";
 import { useCluster } from "@/providers/ClusterProvider";
@@ -11,6 +11,8 @@ import { useBuckyBankProgram } from "@/lib/bucky-bank-data-access";
 export interface DepositParams {
     buckyBankId: string;
     amount: number;
+    /** 存款留言，亲友存款时可附带 */
+    message?: string;
 }
 
 /**
@@ -20,12 +22,12 @@ export interface DepositParams {
 export function useDeposit() {
     const { cluster } = useCluster();
     const provider = useAnchorProvider();
-    const { program, bank_global_stats_accounts } =
+    const { program, programId, bank_global_stats_accounts } =
         useBuckyBankProgram();
 
     return useMutation({
         mutationKey: ["bucky-bank", "deposit", { cluster }],
-        mutationFn: async ({ buckyBankId, amount }: DepositParams) => {
+        mutationFn: async ({ buckyBankId, amount, message }: DepositParams) => {
             if (!provider.publicKey) {
                 throw new Error("You need to connect your wallet first!");
             }
@@ -40,6 +42,19 @@ export function useDeposit() {
             // 获取 BuckyBank PDA
             const buckyBankPDA =  buckyBankId;
 
+            // 获取配捐金库 PDA，孩子存款时合约从此自动配捐
+            const [matchVault] = PublicKey.findProgramAddressSync(
+                [Buffer.from("match_vault"), new PublicKey(buckyBankPDA).toBuffer()],
+                programId
+            );
+
+            // 获取孩子成就账户 PDA，首次存款时由合约创建
+            const buckyBank = await program.account.buckyBankInfo.fetch(buckyBankPDA);
+            const [childAchievements] = PublicKey.findProgramAddressSync(
+                [Buffer.from("child_achievements"), buckyBank.config.childAddress.toBuffer()],
+                programId
+            );
+
             console.log(`Depositing to bucky bank with ID: ${buckyBankId.toString()}`);
             console.log(`Bank Global Stats: ${bankGlobalStats.toBase58()}`);
             console.log(`Bucky Bank PDA: ${buckyBankPDA}`);
@@ -49,11 +64,13 @@ export function useDeposit() {
 
             // 创建存款指令
             const depositIx = await program.methods
-                .deposit(depositAmountBN)
+                .deposit(depositAmountBN, message ? message : null)
                 .accountsStrict({
                     bankGlobalStats,
                     buckyBank: buckyBankPDA,
                     depositor: provider.publicKey,
+                    matchVault,
+                    childAchievements,
                     systemProgram: SystemProgram.programId,
                 })
                 .instruction();
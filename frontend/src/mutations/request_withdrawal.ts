@@ -12,6 +12,8 @@ export interface RequestWithdrawalParams {
     buckyBankId: string;
     amount: number;
     reason: string;
+    // 锁定期内只能申请紧急取款，会按比例扣除罚金
+    emergency?: boolean;
 }
 
 /**
@@ -25,7 +27,7 @@ export function useRequestWithdrawal() {
 
     return useMutation({
         mutationKey: ["bucky-bank", "request_withdrawal", { cluster }],
-        mutationFn: async ({ buckyBankId, amount, reason }: RequestWithdrawalParams) => {
+        mutationFn: async ({ buckyBankId, amount, reason, emergency = false }: RequestWithdrawalParams) => {
             if (!provider.publicKey) {
                 throw new Error("You need to connect your wallet first!");
             }
@@ -34,6 +36,7 @@ export function useRequestWithdrawal() {
             console.log(`Bucky Bank PDA: ${buckyBankId}`);
             console.log(`Amount: ${amount}`);
             console.log(`Reason: ${reason}`);
+            console.log(`Emergency: ${emergency}`);
             console.log(`Requester: ${provider.publicKey}`);
 
             // 获取存钱罐状态以获取 withdrawal_request_counter
@@ -60,7 +63,7 @@ export function useRequestWithdrawal() {
 
             // 创建取款请求指令
             const requestWithdrawalIx = await program.methods
-                .requestWithdrawal(withdrawalAmountBN, reason)
+                .requestWithdrawal(withdrawalAmountBN, reason, emergency)
                 .accountsStrict({
                     buckyBank: buckyBankId,
                     withdrawalRequest: withdrawalRequestPDA,
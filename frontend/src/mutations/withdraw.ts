@@ -19,7 +19,7 @@ export interface WithdrawParams {
 export function useWithdraw() {
     const { cluster } = useCluster();
     const provider = useAnchorProvider();
-    const { program } = useBuckyBankProgram();
+    const { program, bank_global_stats_accounts } = useBuckyBankProgram();
     const queryClient = useQueryClient();
 
     return useMutation({
@@ -34,16 +34,34 @@ export function useWithdraw() {
             console.log(`Bucky Bank ID: ${buckyBankId}`);
             console.log(`Child: ${provider.publicKey}`);
 
+            // 获取 BankGlobalStats 账户
+            const bankGlobalStats = bank_global_stats_accounts.data?.[0]?.publicKey;
+
+            if (!bankGlobalStats) {
+                throw new Error("Bank global stats not found");
+            }
+
             const buckyBankPublicKey = new PublicKey(buckyBankId);
             const withdrawalRequestPublicKey = new PublicKey(requestId);
 
+            // 紧急取款有罚金时需要传入请求记录的罚金收款地址
+            const withdrawalRequest = await program.account.withdrawalRequestInfo.fetch(
+                withdrawalRequestPublicKey
+            );
+            const penaltyRecipient =
+                withdrawalRequest.emergency && withdrawalRequest.penaltyBps > 0
+                    ? withdrawalRequest.penaltyRecipient
+                    : null;
+
             // 创建提现指令
             const withdrawIx = await program.methods
                 .withdraw()
                 .accountsStrict({
+                    bankGlobalStats,
                     buckyBank: buckyBankPublicKey,
                     withdrawalRequest: withdrawalRequestPublicKey,
                     child: provider.publicKey,
+                    penaltyRecipient,
                     systemProgram: SystemProgram.programId,
                 })
                 .instruction();
@@ -84,10 +84,12 @@ export interface WithdrawalRequest {
     amount: number;
     requester: string;
     reason: string;
-    status: 'Pending' | 'Approved' | 'Rejected' | 'Cancelled' | 'Withdrawed';
+    status: 'Pending' | 'Approved' | 'Rejected' | 'Cancelled' | 'Withdrawed' | 'Expired';
     approved_by?: string;
     created_at_ms: number;
     audit_at_ms?: number;
+    expires_at_ms?: number;
+    approved_amount?: number;
     indexed_at: string;
 }
 
@@ -0,0 +1,18 @@
+-- SPL Token / Token-2022 存钱罐：记录存款币种和精度
+-- mint 为 NULL 表示原生 SOL
+
+ALTER TABLE bucky_bank_created_events
+    ADD COLUMN IF NOT EXISTS mint TEXT,
+    ADD COLUMN IF NOT EXISTS decimals SMALLINT NOT NULL DEFAULT 9;
+
+ALTER TABLE deposit_made_events
+    ADD COLUMN IF NOT EXISTS mint TEXT,
+    ADD COLUMN IF NOT EXISTS decimals SMALLINT NOT NULL DEFAULT 9;
+
+ALTER TABLE withdrawal_requests
+    ADD COLUMN IF NOT EXISTS mint TEXT,
+    ADD COLUMN IF NOT EXISTS decimals SMALLINT NOT NULL DEFAULT 9;
+
+ALTER TABLE withdrawed_events
+    ADD COLUMN IF NOT EXISTS mint TEXT,
+    ADD COLUMN IF NOT EXISTS decimals SMALLINT NOT NULL DEFAULT 9;
@@ -0,0 +1,18 @@
+-- 达成目标奖励：存钱罐奖励金额与领取记录
+
+ALTER TABLE bucky_bank_created_events
+    ADD COLUMN IF NOT EXISTS reward_amount BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS reward_claimed BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE IF NOT EXISTS reward_claimed_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    bucky_bank_id TEXT NOT NULL UNIQUE,
+    child TEXT NOT NULL,
+    amount BIGINT NOT NULL,
+    created_at_ms BIGINT NOT NULL,
+    mint TEXT,
+    decimals SMALLINT NOT NULL DEFAULT 9,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_reward_claimed_events_child ON reward_claimed_events (child);
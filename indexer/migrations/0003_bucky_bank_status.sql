@@ -0,0 +1,7 @@
+-- 截止结算：记录存钱罐状态与结算时间
+
+ALTER TABLE bucky_bank_created_events
+    ADD COLUMN IF NOT EXISTS status TEXT NOT NULL DEFAULT 'Active',
+    ADD COLUMN IF NOT EXISTS settled_at_ms BIGINT;
+
+CREATE INDEX IF NOT EXISTS idx_bucky_bank_created_events_status ON bucky_bank_created_events (status);
@@ -0,0 +1,5 @@
+-- 关闭存钱罐：记录关闭时间与余额去向
+
+ALTER TABLE bucky_bank_created_events
+    ADD COLUMN IF NOT EXISTS closed_at_ms BIGINT,
+    ADD COLUMN IF NOT EXISTS closed_payout_to TEXT;
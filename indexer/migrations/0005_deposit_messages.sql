@@ -0,0 +1,7 @@
+-- 亲友存款：存款留言
+
+ALTER TABLE deposit_made_events
+    ADD COLUMN IF NOT EXISTS message TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_deposit_made_events_bank_depositor
+    ON deposit_made_events (bucky_bank_id, depositor);
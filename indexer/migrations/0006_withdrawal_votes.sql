@@ -0,0 +1,18 @@
+-- 多监护人审批：每位监护人的投票记录
+
+CREATE TABLE IF NOT EXISTS withdrawal_votes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    request_id TEXT NOT NULL,
+    bucky_bank_id TEXT NOT NULL,
+    guardian TEXT NOT NULL,
+    approve BOOLEAN NOT NULL,
+    reason TEXT NOT NULL,
+    approvals SMALLINT NOT NULL,
+    rejections SMALLINT NOT NULL,
+    approval_threshold SMALLINT NOT NULL,
+    created_at_ms BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (request_id, guardian)
+);
+
+CREATE INDEX IF NOT EXISTS idx_withdrawal_votes_bucky_bank_id ON withdrawal_votes (bucky_bank_id);
@@ -0,0 +1,4 @@
+-- 自动批准：标记按规则直接批准的取款请求
+
+ALTER TABLE withdrawal_requests
+    ADD COLUMN IF NOT EXISTS auto_approved BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,20 @@
+-- 存钱罐配置变更历史
+
+CREATE TABLE IF NOT EXISTS bucky_bank_config_changes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    bucky_bank_id TEXT NOT NULL,
+    parent TEXT NOT NULL,
+    old_name TEXT NOT NULL,
+    new_name TEXT NOT NULL,
+    old_target_amount BIGINT NOT NULL,
+    new_target_amount BIGINT NOT NULL,
+    old_deadline_ms BIGINT NOT NULL,
+    new_deadline_ms BIGINT NOT NULL,
+    old_status TEXT NOT NULL,
+    new_status TEXT NOT NULL,
+    created_at_ms BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_bucky_bank_config_changes_bucky_bank_id
+    ON bucky_bank_config_changes (bucky_bank_id);
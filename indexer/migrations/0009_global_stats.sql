@@ -0,0 +1,17 @@
+-- 全局统计：按链上 GlobalStatsUpdated 事件携带的增量累加，只保留一行
+
+CREATE TABLE IF NOT EXISTS global_stats (
+    id SMALLINT PRIMARY KEY DEFAULT 1 CHECK (id = 1),
+    total_bucky_banks BIGINT NOT NULL,
+    active_bucky_banks BIGINT NOT NULL,
+    completed_bucky_banks BIGINT NOT NULL,
+    failed_bucky_banks BIGINT NOT NULL,
+    closed_bucky_banks BIGINT NOT NULL,
+    total_deposits BIGINT NOT NULL,
+    total_deposit_amount BIGINT NOT NULL,
+    total_withdrawals BIGINT NOT NULL,
+    total_withdrawal_amount BIGINT NOT NULL,
+    total_value_locked BIGINT NOT NULL,
+    updated_at_ms BIGINT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,7 @@
+-- 存钱罐地址由家长地址和家长的存钱罐 nonce 推导
+
+ALTER TABLE bucky_bank_created_events
+    ADD COLUMN IF NOT EXISTS bank_nonce BIGINT NOT NULL DEFAULT 0;
+
+CREATE INDEX IF NOT EXISTS idx_bucky_bank_created_events_parent_nonce
+    ON bucky_bank_created_events (parent_address, bank_nonce);
@@ -0,0 +1,19 @@
+-- 家长配捐记录
+
+CREATE TABLE IF NOT EXISTS match_credits (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    bucky_bank_id TEXT NOT NULL,
+    parent TEXT NOT NULL,
+    depositor TEXT NOT NULL,
+    deposit_amount BIGINT NOT NULL,
+    match_amount BIGINT NOT NULL,
+    credited_amount BIGINT NOT NULL,
+    skipped BOOLEAN NOT NULL DEFAULT FALSE,
+    match_vault_balance BIGINT NOT NULL,
+    created_at_ms BIGINT NOT NULL,
+    mint TEXT,
+    decimals SMALLINT NOT NULL DEFAULT 9,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_match_credits_bucky_bank_id ON match_credits (bucky_bank_id);
@@ -0,0 +1,21 @@
+-- 存钱罐计息记录
+
+CREATE TABLE IF NOT EXISTS interest_accruals (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    bucky_bank_id TEXT NOT NULL,
+    interest_rate_bps INTEGER NOT NULL,
+    current_balance BIGINT NOT NULL,
+    interest_amount BIGINT NOT NULL,
+    credited_amount BIGINT NOT NULL,
+    skipped BOOLEAN NOT NULL DEFAULT FALSE,
+    interest_pending BIGINT NOT NULL,
+    interest_vault_balance BIGINT NOT NULL,
+    total_interest_earned BIGINT NOT NULL,
+    accrued_by TEXT NOT NULL,
+    created_at_ms BIGINT NOT NULL,
+    mint TEXT,
+    decimals SMALLINT NOT NULL DEFAULT 9,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_interest_accruals_bucky_bank_id ON interest_accruals (bucky_bank_id);
@@ -0,0 +1,18 @@
+-- 存钱罐里程碑达成记录
+
+CREATE TABLE IF NOT EXISTS milestones_reached (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    bucky_bank_id TEXT NOT NULL,
+    milestone_index SMALLINT NOT NULL,
+    amount BIGINT NOT NULL,
+    label TEXT NOT NULL,
+    current_balance BIGINT NOT NULL,
+    target_amount BIGINT NOT NULL,
+    reached_by TEXT NOT NULL,
+    created_at_ms BIGINT NOT NULL,
+    mint TEXT,
+    decimals SMALLINT NOT NULL DEFAULT 9,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_milestones_reached_bucky_bank_id ON milestones_reached (bucky_bank_id);
@@ -0,0 +1,17 @@
+-- 孩子成就徽章解锁记录，每个孩子每种徽章只解锁一次
+
+CREATE TABLE IF NOT EXISTS achievements_unlocked (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    child TEXT NOT NULL,
+    bucky_bank_id TEXT NOT NULL,
+    badge TEXT NOT NULL,
+    current_streak_weeks INTEGER NOT NULL,
+    longest_streak_weeks INTEGER NOT NULL,
+    current_balance BIGINT NOT NULL,
+    target_amount BIGINT NOT NULL,
+    created_at_ms BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (child, badge)
+);
+
+CREATE INDEX IF NOT EXISTS idx_achievements_unlocked_child ON achievements_unlocked (child);
@@ -0,0 +1,4 @@
+-- 取款请求有效期：超过有效期仍未审批的请求会被关闭并标记为 Expired
+
+ALTER TABLE withdrawal_requests
+    ADD COLUMN IF NOT EXISTS expires_at_ms BIGINT;
@@ -0,0 +1,8 @@
+-- 批准金额：家长可以批准少于请求的金额
+
+ALTER TABLE withdrawal_requests
+    ADD COLUMN IF NOT EXISTS approved_amount BIGINT;
+
+UPDATE withdrawal_requests
+SET approved_amount = amount
+WHERE approved_amount IS NULL AND status IN ('Approved', 'Withdrawed');
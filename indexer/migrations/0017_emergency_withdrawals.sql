@@ -0,0 +1,8 @@
+-- 锁定模式：锁定期内的紧急取款按比例扣除罚金，付给家长或指定的慈善地址
+
+ALTER TABLE withdrawal_requests
+    ADD COLUMN IF NOT EXISTS emergency BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE withdrawed_events
+    ADD COLUMN IF NOT EXISTS penalty_amount BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS penalty_recipient TEXT;
@@ -0,0 +1,23 @@
+-- 家长紧急操作：冻结、解冻存钱罐与紧急取款
+
+ALTER TABLE bucky_bank_created_events
+    ADD COLUMN IF NOT EXISTS frozen BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE IF NOT EXISTS bank_emergency_actions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    bucky_bank_id TEXT NOT NULL,
+    parent TEXT NOT NULL,
+    action TEXT NOT NULL, -- Frozen / Unfrozen / EmergencyWithdrawal
+    reason TEXT NOT NULL,
+    destination TEXT,
+    amount BIGINT,
+    left_balance BIGINT,
+    frozen BOOLEAN NOT NULL,
+    created_at_ms BIGINT NOT NULL,
+    mint TEXT,
+    decimals SMALLINT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_bank_emergency_actions_bucky_bank_id ON bank_emergency_actions (bucky_bank_id);
+CREATE INDEX IF NOT EXISTS idx_bank_emergency_actions_created_at_ms ON bank_emergency_actions (created_at_ms DESC);
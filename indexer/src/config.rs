@@ -7,6 +7,7 @@ pub struct Config {
     pub database: DatabaseConfig,
     pub solana: SolanaConfig,
     pub server: ServerConfig,
+    #[allow(dead_code)]
     pub indexing: IndexingConfig,
 }
 
@@ -22,6 +23,7 @@ pub struct DatabaseConfig {
 pub struct SolanaConfig {
     pub rpc_url: String,
     pub program_id: String,
+    #[allow(dead_code)]
     pub query_limit: usize,
 }
 
@@ -31,6 +33,7 @@ pub struct ServerConfig {
     pub port: u16,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 pub struct IndexingConfig {
     pub poll_interval_seconds: u64,
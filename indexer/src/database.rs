@@ -22,7 +22,17 @@ pub mod models {
         pub deadline_ms: i64,
         pub duration_days: i64,
         pub current_balance: i64,
+        pub mint: Option<String>, // None 表示原生 SOL
+        pub decimals: i16,
+        pub reward_amount: i64,
+        pub bank_nonce: i64, // 与 parent_address 一起决定存钱罐地址
+        pub reward_claimed: bool,
+        pub status: String, // Active / Completed / Failed
+        pub settled_at_ms: Option<i64>,
+        pub closed_at_ms: Option<i64>,
+        pub closed_payout_to: Option<String>,
         pub created_at: DateTime<Utc>,
+        pub frozen: bool, // 家长是否已冻结
     }
 
     #[derive(Debug, Serialize, Deserialize)]
@@ -36,6 +46,10 @@ pub mod models {
         pub deadline_ms: i64,
         pub duration_days: i64,
         pub current_balance: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
+        pub reward_amount: i64,
+        pub bank_nonce: i64,
     }
 
     #[derive(Debug, FromRow, Serialize, Deserialize)]
@@ -45,6 +59,9 @@ pub mod models {
         pub amount: i64,
         pub depositor: String,
         pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
+        pub message: Option<String>,
         pub created_at: DateTime<Utc>,
     }
 
@@ -54,6 +71,281 @@ pub mod models {
         pub amount: i64,
         pub depositor: String,
         pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
+        pub message: Option<String>,
+    }
+
+    /// 单个存款人对某个存钱罐的累计贡献
+    #[derive(Debug, FromRow, Serialize, Deserialize)]
+    pub struct DepositContributor {
+        pub depositor: String,
+        pub total_amount: i64,
+        pub deposit_count: i64,
+        pub last_deposit_at_ms: i64,
+    }
+
+    /// 存钱罐配置变更记录
+    #[derive(Debug, FromRow, Serialize, Deserialize)]
+    pub struct BuckyBankConfigChange {
+        pub id: Uuid,
+        pub bucky_bank_id: String,
+        pub parent: String,
+        pub old_name: String,
+        pub new_name: String,
+        pub old_target_amount: i64,
+        pub new_target_amount: i64,
+        pub old_deadline_ms: i64,
+        pub new_deadline_ms: i64,
+        pub old_status: String,
+        pub new_status: String,
+        pub created_at_ms: i64,
+        pub created_at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct NewBuckyBankConfigChange {
+        pub bucky_bank_id: String,
+        pub parent: String,
+        pub old_name: String,
+        pub new_name: String,
+        pub old_target_amount: i64,
+        pub new_target_amount: i64,
+        pub old_deadline_ms: i64,
+        pub new_deadline_ms: i64,
+        pub old_status: String,
+        pub new_status: String,
+        pub created_at_ms: i64,
+    }
+
+    /// 家长配捐记录
+    #[derive(Debug, FromRow, Serialize, Deserialize)]
+    pub struct MatchCredit {
+        pub id: Uuid,
+        pub bucky_bank_id: String,
+        pub parent: String,
+        pub depositor: String,
+        pub deposit_amount: i64,
+        pub match_amount: i64,
+        pub credited_amount: i64,
+        pub skipped: bool,
+        pub match_vault_balance: i64,
+        pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
+        pub created_at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct NewMatchCredit {
+        pub bucky_bank_id: String,
+        pub parent: String,
+        pub depositor: String,
+        pub deposit_amount: i64,
+        pub match_amount: i64,
+        pub credited_amount: i64,
+        pub skipped: bool,
+        pub match_vault_balance: i64,
+        pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
+    }
+
+    /// 存钱罐计息记录
+    #[derive(Debug, FromRow, Serialize, Deserialize)]
+    pub struct InterestAccrual {
+        pub id: Uuid,
+        pub bucky_bank_id: String,
+        pub interest_rate_bps: i32,
+        pub current_balance: i64,
+        pub interest_amount: i64,
+        pub credited_amount: i64,
+        pub skipped: bool,
+        pub interest_pending: i64,
+        pub interest_vault_balance: i64,
+        pub total_interest_earned: i64,
+        pub accrued_by: String,
+        pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
+        pub created_at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct NewInterestAccrual {
+        pub bucky_bank_id: String,
+        pub interest_rate_bps: i32,
+        pub current_balance: i64,
+        pub interest_amount: i64,
+        pub credited_amount: i64,
+        pub skipped: bool,
+        pub interest_pending: i64,
+        pub interest_vault_balance: i64,
+        pub total_interest_earned: i64,
+        pub accrued_by: String,
+        pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
+    }
+
+    /// 里程碑达成记录
+    #[derive(Debug, FromRow, Serialize, Deserialize)]
+    pub struct MilestoneReached {
+        pub id: Uuid,
+        pub bucky_bank_id: String,
+        pub milestone_index: i16,
+        pub amount: i64,
+        pub label: String,
+        pub current_balance: i64,
+        pub target_amount: i64,
+        pub reached_by: String,
+        pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
+        pub created_at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct NewMilestoneReached {
+        pub bucky_bank_id: String,
+        pub milestone_index: i16,
+        pub amount: i64,
+        pub label: String,
+        pub current_balance: i64,
+        pub target_amount: i64,
+        pub reached_by: String,
+        pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
+    }
+
+    /// 家长紧急操作记录：冻结、解冻与紧急取款
+    #[derive(Debug, FromRow, Serialize, Deserialize)]
+    pub struct BankEmergencyAction {
+        pub id: Uuid,
+        pub bucky_bank_id: String,
+        pub parent: String,
+        pub action: String, // Frozen / Unfrozen / EmergencyWithdrawal
+        pub reason: String,
+        pub destination: Option<String>, // 仅紧急取款
+        pub amount: Option<i64>,
+        pub left_balance: Option<i64>,
+        pub frozen: bool, // 操作后存钱罐是否处于冻结状态
+        pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: Option<i16>,
+        pub created_at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct NewBankEmergencyAction {
+        pub bucky_bank_id: String,
+        pub parent: String,
+        pub action: String,
+        pub reason: String,
+        pub destination: Option<String>,
+        pub amount: Option<i64>,
+        pub left_balance: Option<i64>,
+        pub frozen: bool,
+        pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: Option<i16>,
+    }
+
+    /// 孩子成就徽章解锁记录
+    #[derive(Debug, FromRow, Serialize, Deserialize)]
+    pub struct AchievementUnlocked {
+        pub id: Uuid,
+        pub child: String,
+        pub bucky_bank_id: String,
+        pub badge: String,
+        pub current_streak_weeks: i32,
+        pub longest_streak_weeks: i32,
+        pub current_balance: i64,
+        pub target_amount: i64,
+        pub created_at_ms: i64,
+        pub created_at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct NewAchievementUnlocked {
+        pub child: String,
+        pub bucky_bank_id: String,
+        pub badge: String,
+        pub current_streak_weeks: i32,
+        pub longest_streak_weeks: i32,
+        pub current_balance: i64,
+        pub target_amount: i64,
+        pub created_at_ms: i64,
+    }
+
+    /// 全局统计（金额与锁定总额只统计原生 SOL）
+    ///
+    /// 同一结构也表示单个 GlobalStatsUpdated 事件携带的增量。
+    #[derive(Debug, FromRow, Serialize, Deserialize)]
+    pub struct GlobalStats {
+        pub total_bucky_banks: i64,
+        pub active_bucky_banks: i64,
+        pub completed_bucky_banks: i64,
+        pub failed_bucky_banks: i64,
+        pub closed_bucky_banks: i64,
+        pub total_deposits: i64,
+        pub total_deposit_amount: i64,
+        pub total_withdrawals: i64,
+        pub total_withdrawal_amount: i64,
+        pub total_value_locked: i64,
+        pub updated_at_ms: i64,
+    }
+
+    /// 监护人对取款请求的投票
+    #[derive(Debug, FromRow, Serialize, Deserialize)]
+    pub struct WithdrawalVote {
+        pub id: Uuid,
+        pub request_id: String,
+        pub bucky_bank_id: String,
+        pub guardian: String,
+        pub approve: bool,
+        pub reason: String,
+        pub approvals: i16,
+        pub rejections: i16,
+        pub approval_threshold: i16,
+        pub created_at_ms: i64,
+        pub created_at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct NewWithdrawalVote {
+        pub request_id: String,
+        pub bucky_bank_id: String,
+        pub guardian: String,
+        pub approve: bool,
+        pub reason: String,
+        pub approvals: i16,
+        pub rejections: i16,
+        pub approval_threshold: i16,
+        pub created_at_ms: i64,
+    }
+
+    #[derive(Debug, FromRow, Serialize, Deserialize)]
+    pub struct RewardClaimedEvent {
+        pub id: Uuid,
+        pub bucky_bank_id: String,
+        pub child: String,
+        pub amount: i64,
+        pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
+        pub created_at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct NewRewardClaimedEvent {
+        pub bucky_bank_id: String,
+        pub child: String,
+        pub amount: i64,
+        pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
     }
 
     #[derive(Debug, FromRow, Serialize, Deserialize)]
@@ -82,6 +374,7 @@ pub mod models {
         Rejected,   // 已拒绝
         Cancelled,  // 已取消
         Withdrawed, // 已提取
+        Expired,    // 已过期
     }
 
     impl std::fmt::Display for WithdrawalStatus {
@@ -92,6 +385,7 @@ pub mod models {
                 WithdrawalStatus::Rejected => write!(f, "Rejected"),
                 WithdrawalStatus::Cancelled => write!(f, "Cancelled"),
                 WithdrawalStatus::Withdrawed => write!(f, "Withdrawed"),
+                WithdrawalStatus::Expired => write!(f, "Expired"),
             }
         }
     }
@@ -106,6 +400,7 @@ pub mod models {
                 "Rejected" => Ok(WithdrawalStatus::Rejected),
                 "Cancelled" => Ok(WithdrawalStatus::Cancelled),
                 "Withdrawed" => Ok(WithdrawalStatus::Withdrawed),
+                "Expired" => Ok(WithdrawalStatus::Expired),
                 _ => Err(format!("Invalid withdrawal status: {}", s)),
             }
         }
@@ -123,10 +418,17 @@ pub mod models {
         pub approved_by: Option<String>,
         pub created_at_ms: i64,
         pub audit_at_ms: Option<i64>,        // 审批时间
+        pub mint: Option<String>,
+        pub decimals: i16,
+        pub auto_approved: bool,             // 是否按自动批准规则直接批准
+        pub expires_at_ms: Option<i64>,      // 过期时间
+        pub approved_amount: Option<i64>,    // 批准金额，可小于请求金额
+        pub emergency: bool,                 // 是否为锁定期内的紧急取款
         pub indexed_at: Option<DateTime<Utc>>,
     }
 
     impl WithdrawalRequestEvent {
+        #[allow(dead_code)]
         pub fn get_status(&self) -> Result<WithdrawalStatus, String> {
             self.status.parse()
         }
@@ -143,6 +445,11 @@ pub mod models {
         pub approved_by: String,
         pub created_at_ms: i64,
         pub audit_at_ms: Option<i64>,        // 审批时间
+        pub mint: Option<String>,
+        pub decimals: i16,
+        pub auto_approved: bool,
+        pub expires_at_ms: Option<i64>,
+        pub emergency: bool,
         pub tx_digest: String,
         pub event_seq: i64,
         pub timestamp_ms: i64,
@@ -158,7 +465,11 @@ pub mod models {
         pub left_balance: i64,
         pub withdrawer: String,
         pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
         pub created_at: DateTime<Utc>,
+        pub penalty_amount: i64,               // 紧急取款罚金
+        pub penalty_recipient: Option<String>, // 罚金收款地址
     }
 
     #[derive(Debug, Serialize, Deserialize)]
@@ -169,6 +480,10 @@ pub mod models {
         pub left_balance: i64,
         pub withdrawer: String,
         pub created_at_ms: i64,
+        pub mint: Option<String>,
+        pub decimals: i16,
+        pub penalty_amount: i64,
+        pub penalty_recipient: Option<String>,
     }
 }
 
@@ -199,8 +514,9 @@ impl Database {
             r#"
             INSERT INTO bucky_bank_created_events (
                 bucky_bank_id, name, parent_address, child_address,
-                target_amount, created_at_ms, deadline_ms, duration_days, current_balance
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                target_amount, created_at_ms, deadline_ms, duration_days, current_balance,
+                mint, decimals, reward_amount, bank_nonce
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
             RETURNING *
             "#,
         )
@@ -213,6 +529,10 @@ impl Database {
         .bind(event.deadline_ms)
         .bind(event.duration_days)
         .bind(event.current_balance)
+        .bind(&event.mint)
+        .bind(event.decimals)
+        .bind(event.reward_amount)
+        .bind(event.bank_nonce)
         .fetch_one(&self.pool)
         .await?;
 
@@ -229,8 +549,8 @@ impl Database {
         let deposit_result = sqlx::query_as::<_, models::DepositMadeEvent>(
             r#"
             INSERT INTO deposit_made_events (
-                bucky_bank_id, amount, depositor, created_at_ms
-            ) VALUES ($1, $2, $3, $4)
+                bucky_bank_id, amount, depositor, created_at_ms, mint, decimals, message
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7)
             RETURNING *
             "#,
         )
@@ -238,6 +558,9 @@ impl Database {
         .bind(event.amount)
         .bind(&event.depositor)
         .bind(event.created_at_ms)
+        .bind(&event.mint)
+        .bind(event.decimals)
+        .bind(&event.message)
         .fetch_one(&mut *transaction)
         .await?;
 
@@ -266,6 +589,56 @@ impl Database {
         Ok(deposit_result)
     }
 
+    // 根据bucky_bank_id查询存款事件列表
+    pub async fn get_deposits_by_bank_id(
+        &self,
+        bucky_bank_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> Result<Vec<models::DepositMadeEvent>> {
+        let query = r#"
+            SELECT * FROM deposit_made_events
+            WHERE bucky_bank_id = $1
+            ORDER BY created_at_ms DESC
+            LIMIT $2 OFFSET $3
+        "#;
+
+        let result = sqlx::query_as::<_, models::DepositMadeEvent>(query)
+            .bind(bucky_bank_id)
+            .bind(limit.unwrap_or(50))
+            .bind(offset.unwrap_or(0))
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    // 按存款人汇总某个存钱罐的贡献
+    pub async fn get_contributors_by_bank_id(
+        &self,
+        bucky_bank_id: &str,
+    ) -> Result<Vec<models::DepositContributor>> {
+        let query = r#"
+            SELECT
+                depositor,
+                COALESCE(SUM(amount), 0)::BIGINT as total_amount,
+                COUNT(*) as deposit_count,
+                MAX(created_at_ms) as last_deposit_at_ms
+            FROM deposit_made_events
+            WHERE bucky_bank_id = $1
+            GROUP BY depositor
+            ORDER BY total_amount DESC
+        "#;
+
+        let result = sqlx::query_as::<_, models::DepositContributor>(query)
+            .bind(bucky_bank_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[allow(dead_code)]
     pub async fn save_bucky_bank_created_events_batch(
         &self,
         events: &[models::NewBuckyBankCreatedEvent],
@@ -278,8 +651,8 @@ impl Database {
                 r#"
                 INSERT INTO bucky_bank_created_events (
                     bucky_bank_id, parent_address, child_address,
-                    target_amount, deadline_ms
-                ) VALUES ($1, $2, $3, $4, $5)
+                    target_amount, deadline_ms, mint, decimals, reward_amount, bank_nonce
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
                 ON CONFLICT (bucky_bank_id) DO NOTHING
                 "#,
             )
@@ -288,6 +661,10 @@ impl Database {
             .bind(&event.child_address)
             .bind(event.target_amount)
             .bind(event.deadline_ms)
+            .bind(&event.mint)
+            .bind(event.decimals)
+            .bind(event.reward_amount)
+            .bind(event.bank_nonce)
             .execute(&mut *transaction)
             .await?;
 
@@ -298,6 +675,7 @@ impl Database {
         Ok(count)
     }
 
+    #[allow(dead_code)]
     pub async fn save_deposit_made_events_batch(
         &self,
         events: &[models::NewDepositMadeEvent],
@@ -310,14 +688,17 @@ impl Database {
             sqlx::query(
                 r#"
                 INSERT INTO deposit_made_events (
-                    bucky_bank_id, amount, depositor, created_at_ms
-                ) VALUES ($1, $2, $3, $4)
+                    bucky_bank_id, amount, depositor, created_at_ms, mint, decimals, message
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7)
                 "#,
             )
             .bind(&event.bucky_bank_id)
             .bind(event.amount)
             .bind(&event.depositor)
             .bind(event.created_at_ms)
+            .bind(&event.mint)
+            .bind(event.decimals)
+            .bind(&event.message)
             .execute(&mut *transaction)
             .await?;
 
@@ -344,113 +725,683 @@ impl Database {
             count += 1;
         }
 
-        transaction.commit().await?;
-        Ok(count)
-    }
+        transaction.commit().await?;
+        Ok(count)
+    }
+
+    pub async fn get_latest_event_timestamp(&self) -> Result<Option<i64>> {
+        let result = sqlx::query(
+            "SELECT EXTRACT(EPOCH FROM created_at) * 1000 as created_at_ms
+             FROM bucky_bank_created_events
+             ORDER BY created_at DESC
+             LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result.map(|row| row.get::<i64, _>("created_at_ms")))
+    }
+
+    pub async fn health_check(&self) -> Result<()> {
+        sqlx::query("SELECT 1").fetch_one(&self.pool).await?;
+        Ok(())
+    }
+
+    #[allow(dead_code)]
+    pub fn get_pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    // 游标相关操作方法
+    pub async fn get_cursor(&self, id: &str) -> Result<Option<models::Cursor>> {
+        let result = sqlx::query_as::<_, models::Cursor>("SELECT * FROM cursors WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn save_cursor(&self, cursor: &models::NewCursor) -> Result<models::Cursor> {
+        let result = sqlx::query_as::<_, models::Cursor>(
+            r#"
+            INSERT INTO cursors (id, last_processed_signature, last_processed_slot, total_events_processed, last_poll_time)
+            VALUES ($1, $2, $3, $4, CURRENT_TIMESTAMP)
+            ON CONFLICT (id) DO UPDATE SET
+                last_processed_signature = EXCLUDED.last_processed_signature,
+                last_processed_slot = EXCLUDED.last_processed_slot,
+                total_events_processed = EXCLUDED.total_events_processed,
+                last_poll_time = CURRENT_TIMESTAMP,
+                updated_at = CURRENT_TIMESTAMP
+            RETURNING *
+            "#,
+        )
+        .bind(&cursor.id)
+        .bind(&cursor.last_processed_signature)
+        .bind(cursor.last_processed_slot)
+        .bind(cursor.total_events_processed)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[allow(dead_code)]
+    pub async fn update_cursor(
+        &self,
+        id: &str,
+        event_seq: &str,
+        tx_digest: &str,
+    ) -> Result<Option<models::Cursor>> {
+        let result = sqlx::query_as::<_, models::Cursor>(
+            r#"
+            UPDATE cursors SET
+                event_seq = $2,
+                tx_digest = $3,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(event_seq)
+        .bind(tx_digest)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    // 查询BuckyBank的当前余额
+    #[allow(dead_code)]
+    pub async fn get_bucky_bank_balance(&self, bucky_bank_id: &str) -> Result<Option<i64>> {
+        let result = sqlx::query(
+            "SELECT current_balance FROM bucky_bank_created_events WHERE bucky_bank_id = $1",
+        )
+        .bind(bucky_bank_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result.map(|row| row.get::<i64, _>("current_balance")))
+    }
+
+    // 直接更新BuckyBank余额
+    pub async fn update_bucky_bank_balance(
+        &self,
+        bucky_bank_id: &str,
+        new_balance: i64,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            "UPDATE bucky_bank_created_events SET current_balance = $1 WHERE bucky_bank_id = $2",
+        )
+        .bind(new_balance)
+        .bind(bucky_bank_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 更新BuckyBank达成目标奖励金额
+    pub async fn update_bucky_bank_reward_amount(
+        &self,
+        bucky_bank_id: &str,
+        reward_amount: i64,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            "UPDATE bucky_bank_created_events SET reward_amount = $1 WHERE bucky_bank_id = $2",
+        )
+        .bind(reward_amount)
+        .bind(bucky_bank_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 保存配置变更记录，并同步更新BuckyBank当前配置
+    pub async fn save_bucky_bank_config_change(
+        &self,
+        change: &models::NewBuckyBankConfigChange,
+    ) -> Result<models::BuckyBankConfigChange> {
+        let mut transaction = self.pool.begin().await?;
+
+        // 1. 插入配置变更记录
+        let change_result = sqlx::query_as::<_, models::BuckyBankConfigChange>(
+            r#"
+            INSERT INTO bucky_bank_config_changes (
+                bucky_bank_id, parent, old_name, new_name, old_target_amount, new_target_amount,
+                old_deadline_ms, new_deadline_ms, old_status, new_status, created_at_ms
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING *
+            "#,
+        )
+        .bind(&change.bucky_bank_id)
+        .bind(&change.parent)
+        .bind(&change.old_name)
+        .bind(&change.new_name)
+        .bind(change.old_target_amount)
+        .bind(change.new_target_amount)
+        .bind(change.old_deadline_ms)
+        .bind(change.new_deadline_ms)
+        .bind(&change.old_status)
+        .bind(&change.new_status)
+        .bind(change.created_at_ms)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        // 2. 同步更新BuckyBank当前配置
+        let update_result = sqlx::query(
+            r#"
+            UPDATE bucky_bank_created_events
+            SET name = $1, target_amount = $2, deadline_ms = $3, status = $4
+            WHERE bucky_bank_id = $5
+            "#,
+        )
+        .bind(&change.new_name)
+        .bind(change.new_target_amount)
+        .bind(change.new_deadline_ms)
+        .bind(&change.new_status)
+        .bind(&change.bucky_bank_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        if update_result.rows_affected() == 0 {
+            return Err(anyhow::anyhow!(
+                "BuckyBank with id {} not found",
+                change.bucky_bank_id
+            ));
+        }
+
+        transaction.commit().await?;
+        Ok(change_result)
+    }
+
+    // 根据bucky_bank_id查询配置变更历史
+    pub async fn get_config_changes_by_bank_id(
+        &self,
+        bucky_bank_id: &str,
+    ) -> Result<Vec<models::BuckyBankConfigChange>> {
+        let result = sqlx::query_as::<_, models::BuckyBankConfigChange>(
+            "SELECT * FROM bucky_bank_config_changes WHERE bucky_bank_id = $1 ORDER BY created_at_ms DESC"
+        )
+        .bind(bucky_bank_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn save_match_credit(
+        &self,
+        credit: &models::NewMatchCredit,
+    ) -> Result<models::MatchCredit> {
+        let mut transaction = self.pool.begin().await?;
+
+        // 1. 插入配捐记录
+        let credit_result = sqlx::query_as::<_, models::MatchCredit>(
+            r#"
+            INSERT INTO match_credits (
+                bucky_bank_id, parent, depositor, deposit_amount, match_amount, credited_amount,
+                skipped, match_vault_balance, created_at_ms, mint, decimals
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING *
+            "#,
+        )
+        .bind(&credit.bucky_bank_id)
+        .bind(&credit.parent)
+        .bind(&credit.depositor)
+        .bind(credit.deposit_amount)
+        .bind(credit.match_amount)
+        .bind(credit.credited_amount)
+        .bind(credit.skipped)
+        .bind(credit.match_vault_balance)
+        .bind(credit.created_at_ms)
+        .bind(&credit.mint)
+        .bind(credit.decimals)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        // 2. 配捐计入bucky_bank的当前余额
+        sqlx::query(
+            r#"
+            UPDATE bucky_bank_created_events
+            SET current_balance = current_balance + $1
+            WHERE bucky_bank_id = $2
+            "#,
+        )
+        .bind(credit.credited_amount)
+        .bind(&credit.bucky_bank_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+        Ok(credit_result)
+    }
+
+    // 根据bucky_bank_id查询配捐记录
+    pub async fn get_match_credits_by_bank_id(
+        &self,
+        bucky_bank_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> Result<Vec<models::MatchCredit>> {
+        let result = sqlx::query_as::<_, models::MatchCredit>(
+            r#"
+            SELECT * FROM match_credits
+            WHERE bucky_bank_id = $1
+            ORDER BY created_at_ms DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(bucky_bank_id)
+        .bind(limit.unwrap_or(50))
+        .bind(offset.unwrap_or(0))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn save_interest_accrual(
+        &self,
+        accrual: &models::NewInterestAccrual,
+    ) -> Result<models::InterestAccrual> {
+        let mut transaction = self.pool.begin().await?;
+
+        // 1. 插入计息记录
+        let accrual_result = sqlx::query_as::<_, models::InterestAccrual>(
+            r#"
+            INSERT INTO interest_accruals (
+                bucky_bank_id, interest_rate_bps, current_balance, interest_amount, credited_amount,
+                skipped, interest_pending, interest_vault_balance, total_interest_earned, accrued_by,
+                created_at_ms, mint, decimals
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            RETURNING *
+            "#,
+        )
+        .bind(&accrual.bucky_bank_id)
+        .bind(accrual.interest_rate_bps)
+        .bind(accrual.current_balance)
+        .bind(accrual.interest_amount)
+        .bind(accrual.credited_amount)
+        .bind(accrual.skipped)
+        .bind(accrual.interest_pending)
+        .bind(accrual.interest_vault_balance)
+        .bind(accrual.total_interest_earned)
+        .bind(&accrual.accrued_by)
+        .bind(accrual.created_at_ms)
+        .bind(&accrual.mint)
+        .bind(accrual.decimals)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        // 2. 利息计入bucky_bank的当前余额
+        sqlx::query(
+            r#"
+            UPDATE bucky_bank_created_events
+            SET current_balance = current_balance + $1
+            WHERE bucky_bank_id = $2
+            "#,
+        )
+        .bind(accrual.credited_amount)
+        .bind(&accrual.bucky_bank_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+        Ok(accrual_result)
+    }
+
+    // 根据bucky_bank_id查询计息记录
+    pub async fn get_interest_accruals_by_bank_id(
+        &self,
+        bucky_bank_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> Result<Vec<models::InterestAccrual>> {
+        let result = sqlx::query_as::<_, models::InterestAccrual>(
+            r#"
+            SELECT * FROM interest_accruals
+            WHERE bucky_bank_id = $1
+            ORDER BY created_at_ms DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(bucky_bank_id)
+        .bind(limit.unwrap_or(50))
+        .bind(offset.unwrap_or(0))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn save_milestone_reached(
+        &self,
+        milestone: &models::NewMilestoneReached,
+    ) -> Result<models::MilestoneReached> {
+        let result = sqlx::query_as::<_, models::MilestoneReached>(
+            r#"
+            INSERT INTO milestones_reached (
+                bucky_bank_id, milestone_index, amount, label, current_balance, target_amount,
+                reached_by, created_at_ms, mint, decimals
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING *
+            "#,
+        )
+        .bind(&milestone.bucky_bank_id)
+        .bind(milestone.milestone_index)
+        .bind(milestone.amount)
+        .bind(&milestone.label)
+        .bind(milestone.current_balance)
+        .bind(milestone.target_amount)
+        .bind(&milestone.reached_by)
+        .bind(milestone.created_at_ms)
+        .bind(&milestone.mint)
+        .bind(milestone.decimals)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    // 根据bucky_bank_id查询里程碑达成记录
+    pub async fn get_milestones_reached_by_bank_id(
+        &self,
+        bucky_bank_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> Result<Vec<models::MilestoneReached>> {
+        let result = sqlx::query_as::<_, models::MilestoneReached>(
+            r#"
+            SELECT * FROM milestones_reached
+            WHERE bucky_bank_id = $1
+            ORDER BY created_at_ms DESC, milestone_index DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(bucky_bank_id)
+        .bind(limit.unwrap_or(50))
+        .bind(offset.unwrap_or(0))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    // 保存成就解锁记录，重复索引同一事件时忽略
+    pub async fn save_achievement_unlocked(
+        &self,
+        achievement: &models::NewAchievementUnlocked,
+    ) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO achievements_unlocked (
+                child, bucky_bank_id, badge, current_streak_weeks, longest_streak_weeks,
+                current_balance, target_amount, created_at_ms
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (child, badge) DO NOTHING
+            "#,
+        )
+        .bind(&achievement.child)
+        .bind(&achievement.bucky_bank_id)
+        .bind(&achievement.badge)
+        .bind(achievement.current_streak_weeks)
+        .bind(achievement.longest_streak_weeks)
+        .bind(achievement.current_balance)
+        .bind(achievement.target_amount)
+        .bind(achievement.created_at_ms)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // 根据孩子地址查询已解锁的成就
+    pub async fn get_achievements_by_child(
+        &self,
+        child: &str,
+    ) -> Result<Vec<models::AchievementUnlocked>> {
+        let result = sqlx::query_as::<_, models::AchievementUnlocked>(
+            r#"
+            SELECT * FROM achievements_unlocked
+            WHERE child = $1
+            ORDER BY created_at_ms ASC
+            "#,
+        )
+        .bind(child)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn save_bank_emergency_action(
+        &self,
+        action: &models::NewBankEmergencyAction,
+    ) -> Result<models::BankEmergencyAction> {
+        let mut transaction = self.pool.begin().await?;
+
+        // 1. 插入紧急操作记录
+        let action_result = sqlx::query_as::<_, models::BankEmergencyAction>(
+            r#"
+            INSERT INTO bank_emergency_actions (
+                bucky_bank_id, parent, action, reason, destination, amount, left_balance,
+                frozen, created_at_ms, mint, decimals
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING *
+            "#,
+        )
+        .bind(&action.bucky_bank_id)
+        .bind(&action.parent)
+        .bind(&action.action)
+        .bind(&action.reason)
+        .bind(&action.destination)
+        .bind(action.amount)
+        .bind(action.left_balance)
+        .bind(action.frozen)
+        .bind(action.created_at_ms)
+        .bind(&action.mint)
+        .bind(action.decimals)
+        .fetch_one(&mut *transaction)
+        .await?;
 
-    pub async fn get_latest_event_timestamp(&self) -> Result<Option<i64>> {
-        let result = sqlx::query(
-            "SELECT EXTRACT(EPOCH FROM created_at) * 1000 as created_at_ms
-             FROM bucky_bank_created_events
-             ORDER BY created_at DESC
-             LIMIT 1",
+        // 2. 同步存钱罐的冻结状态，紧急取款同时更新余额
+        sqlx::query(
+            r#"
+            UPDATE bucky_bank_created_events
+            SET frozen = $1, current_balance = COALESCE($2, current_balance)
+            WHERE bucky_bank_id = $3
+            "#,
         )
-        .fetch_optional(&self.pool)
+        .bind(action.frozen)
+        .bind(action.left_balance)
+        .bind(&action.bucky_bank_id)
+        .execute(&mut *transaction)
         .await?;
 
-        Ok(result.map(|row| row.get::<i64, _>("created_at_ms")))
+        transaction.commit().await?;
+        Ok(action_result)
     }
 
-    pub async fn health_check(&self) -> Result<()> {
-        sqlx::query("SELECT 1").fetch_one(&self.pool).await?;
-        Ok(())
-    }
+    // 根据bucky_bank_id查询紧急操作记录
+    pub async fn get_emergency_actions_by_bank_id(
+        &self,
+        bucky_bank_id: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> Result<Vec<models::BankEmergencyAction>> {
+        let result = sqlx::query_as::<_, models::BankEmergencyAction>(
+            r#"
+            SELECT * FROM bank_emergency_actions
+            WHERE bucky_bank_id = $1
+            ORDER BY created_at_ms DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(bucky_bank_id)
+        .bind(limit.unwrap_or(50))
+        .bind(offset.unwrap_or(0))
+        .fetch_all(&self.pool)
+        .await?;
 
-    pub fn get_pool(&self) -> &PgPool {
-        &self.pool
+        Ok(result)
     }
 
-    // 游标相关操作方法
-    pub async fn get_cursor(&self, id: &str) -> Result<Option<models::Cursor>> {
-        let result = sqlx::query_as::<_, models::Cursor>("SELECT * FROM cursors WHERE id = $1")
-            .bind(id)
-            .fetch_optional(&self.pool)
-            .await?;
+    // 查询所有存钱罐最近的紧急操作记录
+    pub async fn get_recent_emergency_actions(
+        &self,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> Result<Vec<models::BankEmergencyAction>> {
+        let result = sqlx::query_as::<_, models::BankEmergencyAction>(
+            r#"
+            SELECT * FROM bank_emergency_actions
+            ORDER BY created_at_ms DESC
+            LIMIT $1 OFFSET $2
+            "#,
+        )
+        .bind(limit.unwrap_or(50))
+        .bind(offset.unwrap_or(0))
+        .fetch_all(&self.pool)
+        .await?;
 
         Ok(result)
     }
 
-    pub async fn save_cursor(&self, cursor: &models::NewCursor) -> Result<models::Cursor> {
-        let result = sqlx::query_as::<_, models::Cursor>(
+    // 累加一条全局统计增量
+    pub async fn apply_global_stats_delta(&self, delta: &models::GlobalStats) -> Result<()> {
+        sqlx::query(
             r#"
-            INSERT INTO cursors (id, last_processed_signature, last_processed_slot, total_events_processed, last_poll_time)
-            VALUES ($1, $2, $3, $4, CURRENT_TIMESTAMP)
+            INSERT INTO global_stats (
+                id, total_bucky_banks, active_bucky_banks, completed_bucky_banks, failed_bucky_banks,
+                closed_bucky_banks, total_deposits, total_deposit_amount, total_withdrawals,
+                total_withdrawal_amount, total_value_locked, updated_at_ms
+            ) VALUES (1, $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             ON CONFLICT (id) DO UPDATE SET
-                last_processed_signature = EXCLUDED.last_processed_signature,
-                last_processed_slot = EXCLUDED.last_processed_slot,
-                total_events_processed = EXCLUDED.total_events_processed,
-                last_poll_time = CURRENT_TIMESTAMP,
+                total_bucky_banks = global_stats.total_bucky_banks + EXCLUDED.total_bucky_banks,
+                active_bucky_banks = global_stats.active_bucky_banks + EXCLUDED.active_bucky_banks,
+                completed_bucky_banks = global_stats.completed_bucky_banks + EXCLUDED.completed_bucky_banks,
+                failed_bucky_banks = global_stats.failed_bucky_banks + EXCLUDED.failed_bucky_banks,
+                closed_bucky_banks = global_stats.closed_bucky_banks + EXCLUDED.closed_bucky_banks,
+                total_deposits = global_stats.total_deposits + EXCLUDED.total_deposits,
+                total_deposit_amount = global_stats.total_deposit_amount + EXCLUDED.total_deposit_amount,
+                total_withdrawals = global_stats.total_withdrawals + EXCLUDED.total_withdrawals,
+                total_withdrawal_amount = global_stats.total_withdrawal_amount + EXCLUDED.total_withdrawal_amount,
+                total_value_locked = global_stats.total_value_locked + EXCLUDED.total_value_locked,
+                updated_at_ms = GREATEST(global_stats.updated_at_ms, EXCLUDED.updated_at_ms),
                 updated_at = CURRENT_TIMESTAMP
-            RETURNING *
             "#,
         )
-        .bind(&cursor.id)
-        .bind(&cursor.last_processed_signature)
-        .bind(&cursor.last_processed_slot)
-        .bind(&cursor.total_events_processed)
-        .fetch_one(&self.pool)
+        .bind(delta.total_bucky_banks)
+        .bind(delta.active_bucky_banks)
+        .bind(delta.completed_bucky_banks)
+        .bind(delta.failed_bucky_banks)
+        .bind(delta.closed_bucky_banks)
+        .bind(delta.total_deposits)
+        .bind(delta.total_deposit_amount)
+        .bind(delta.total_withdrawals)
+        .bind(delta.total_withdrawal_amount)
+        .bind(delta.total_value_locked)
+        .bind(delta.updated_at_ms)
+        .execute(&self.pool)
         .await?;
 
-        Ok(result)
+        Ok(())
     }
 
-    pub async fn update_cursor(
-        &self,
-        id: &str,
-        event_seq: &str,
-        tx_digest: &str,
-    ) -> Result<Option<models::Cursor>> {
-        let result = sqlx::query_as::<_, models::Cursor>(
+    // 获取全局统计
+    pub async fn get_global_stats(&self) -> Result<Option<models::GlobalStats>> {
+        let result = sqlx::query_as::<_, models::GlobalStats>(
             r#"
-            UPDATE cursors SET
-                event_seq = $2,
-                tx_digest = $3,
-                updated_at = CURRENT_TIMESTAMP
-            WHERE id = $1
-            RETURNING *
+            SELECT total_bucky_banks, active_bucky_banks, completed_bucky_banks, failed_bucky_banks,
+                closed_bucky_banks, total_deposits, total_deposit_amount, total_withdrawals,
+                total_withdrawal_amount, total_value_locked, updated_at_ms
+            FROM global_stats WHERE id = 1
             "#,
         )
-        .bind(id)
-        .bind(event_seq)
-        .bind(tx_digest)
         .fetch_optional(&self.pool)
         .await?;
 
         Ok(result)
     }
 
-    // 查询BuckyBank的当前余额
-    pub async fn get_bucky_bank_balance(&self, bucky_bank_id: &str) -> Result<Option<i64>> {
+    // 家长角色转移后更新BuckyBank的家长地址
+    pub async fn update_bucky_bank_parent(
+        &self,
+        bucky_bank_id: &str,
+        parent_address: &str,
+    ) -> Result<bool> {
         let result = sqlx::query(
-            "SELECT current_balance FROM bucky_bank_created_events WHERE bucky_bank_id = $1",
+            "UPDATE bucky_bank_created_events SET parent_address = $1 WHERE bucky_bank_id = $2",
         )
+        .bind(parent_address)
         .bind(bucky_bank_id)
-        .fetch_optional(&self.pool)
+        .execute(&self.pool)
         .await?;
 
-        Ok(result.map(|row| row.get::<i64, _>("current_balance")))
+        Ok(result.rows_affected() > 0)
     }
 
-    // 直接更新BuckyBank余额
-    pub async fn update_bucky_bank_balance(
+    // 更换孩子地址后更新BuckyBank的孩子地址
+    pub async fn update_bucky_bank_child(
         &self,
         bucky_bank_id: &str,
-        new_balance: i64,
+        child_address: &str,
     ) -> Result<bool> {
         let result = sqlx::query(
-            "UPDATE bucky_bank_created_events SET current_balance = $1 WHERE bucky_bank_id = $2",
+            "UPDATE bucky_bank_created_events SET child_address = $1 WHERE bucky_bank_id = $2",
         )
-        .bind(new_balance)
+        .bind(child_address)
+        .bind(bucky_bank_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 根据结算结果更新BuckyBank状态与余额
+    pub async fn update_bucky_bank_status(
+        &self,
+        bucky_bank_id: &str,
+        status: &str,
+        current_balance: i64,
+        settled_at_ms: i64,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE bucky_bank_created_events
+            SET status = $1, current_balance = $2, settled_at_ms = $3
+            WHERE bucky_bank_id = $4
+            "#,
+        )
+        .bind(status)
+        .bind(current_balance)
+        .bind(settled_at_ms)
+        .bind(bucky_bank_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 标记BuckyBank已关闭，余额已全部付出
+    pub async fn close_bucky_bank(
+        &self,
+        bucky_bank_id: &str,
+        payout_to: &str,
+        closed_at_ms: i64,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE bucky_bank_created_events
+            SET status = 'Closed', current_balance = 0, closed_payout_to = $1, closed_at_ms = $2
+            WHERE bucky_bank_id = $3
+            "#,
+        )
+        .bind(payout_to)
+        .bind(closed_at_ms)
         .bind(bucky_bank_id)
         .execute(&self.pool)
         .await?;
@@ -459,6 +1410,7 @@ impl Database {
     }
 
     // 通过存款事件重新计算并更新余额（用于数据一致性修复）
+    #[allow(dead_code)]
     pub async fn recalculate_bucky_bank_balance(&self, bucky_bank_id: &str) -> Result<bool> {
         // 重新计算总存款金额
         let total_deposits: Option<i64> = sqlx::query(
@@ -493,6 +1445,7 @@ impl Database {
         }
     }
 
+    #[allow(dead_code)]
     pub async fn delete_cursor(&self, id: &str) -> Result<bool> {
         let result = sqlx::query("DELETE FROM cursors WHERE id = $1")
             .bind(id)
@@ -502,6 +1455,7 @@ impl Database {
         Ok(result.rows_affected() > 0)
     }
 
+    #[allow(dead_code)]
     pub async fn list_cursors(&self, limit: Option<i64>) -> Result<Vec<models::Cursor>> {
         let query = if let Some(_limit) = limit {
             "SELECT * FROM cursors ORDER BY updated_at DESC LIMIT $1"
@@ -528,8 +1482,9 @@ impl Database {
             r#"
             INSERT INTO withdrawal_requests (
                 request_id, bucky_bank_id, amount, requester, reason,
-                status, approved_by, created_at_ms, audit_at_ms
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                status, approved_by, created_at_ms, audit_at_ms, mint, decimals, auto_approved,
+                expires_at_ms, emergency
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
             RETURNING *
             "#,
         )
@@ -542,12 +1497,18 @@ impl Database {
         .bind(&event.approved_by)
         .bind(event.created_at_ms)
         .bind(event.audit_at_ms) // 添加audit_at_ms字段
+        .bind(&event.mint)
+        .bind(event.decimals)
+        .bind(event.auto_approved)
+        .bind(event.expires_at_ms)
+        .bind(event.emergency)
         .fetch_one(&self.pool)
         .await?;
 
         Ok(result)
     }
 
+    #[allow(dead_code)]
     pub async fn save_withdrawal_request_events_batch(
         &self,
         events: &[models::NewWithdrawalRequestEvent],
@@ -560,8 +1521,9 @@ impl Database {
                 r#"
                 INSERT INTO withdrawal_requests (
                     request_id, bucky_bank_id, amount, requester, reason,
-                    status, approved_by, created_at_ms
-                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                    status, approved_by, created_at_ms, mint, decimals, auto_approved, expires_at_ms,
+                    emergency
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
                 ON CONFLICT (request_id) DO NOTHING
                 "#,
             )
@@ -573,6 +1535,11 @@ impl Database {
             .bind(event.status.to_string())
             .bind(&event.approved_by)
             .bind(event.created_at_ms)
+            .bind(&event.mint)
+            .bind(event.decimals)
+            .bind(event.auto_approved)
+            .bind(event.expires_at_ms)
+            .bind(event.emergency)
             .execute(&mut *transaction)
             .await?;
 
@@ -695,6 +1662,138 @@ impl Database {
         Ok(result.rows_affected() > 0)
     }
 
+    // 将提取请求标记为已批准并记录批准金额
+    pub async fn approve_withdrawal_request(
+        &self,
+        request_id: &str,
+        approved_by: &str,
+        approved_amount: i64,
+        audit_at_ms: i64,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE withdrawal_requests
+            SET status = 'Approved', approved_by = $1, approved_amount = $2, audit_at_ms = $3
+            WHERE request_id = $4
+            "#,
+        )
+        .bind(approved_by)
+        .bind(approved_amount)
+        .bind(audit_at_ms)
+        .bind(request_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 标记提取请求为自动批准
+    pub async fn mark_withdrawal_request_auto_approved(
+        &self,
+        request_id: &str,
+        audit_at_ms: i64,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE withdrawal_requests
+            SET status = 'Approved', auto_approved = TRUE, audit_at_ms = $1, approved_amount = amount
+            WHERE request_id = $2 AND status IN ('Pending', 'Approved')
+            "#,
+        )
+        .bind(audit_at_ms)
+        .bind(request_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 将待审批或已批准的提取请求标记为已取消
+    pub async fn cancel_withdrawal_request(
+        &self,
+        request_id: &str,
+        cancelled_at_ms: i64,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE withdrawal_requests
+            SET status = 'Cancelled', audit_at_ms = $1
+            WHERE request_id = $2 AND status IN ('Pending', 'Approved')
+            "#,
+        )
+        .bind(cancelled_at_ms)
+        .bind(request_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 将待审批的提取请求标记为已过期
+    pub async fn expire_withdrawal_request(
+        &self,
+        request_id: &str,
+        expired_at_ms: i64,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE withdrawal_requests
+            SET status = 'Expired', audit_at_ms = $1
+            WHERE request_id = $2 AND status = 'Pending'
+            "#,
+        )
+        .bind(expired_at_ms)
+        .bind(request_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    // 保存监护人投票
+    pub async fn save_withdrawal_vote(
+        &self,
+        vote: &models::NewWithdrawalVote,
+    ) -> Result<models::WithdrawalVote> {
+        let result = sqlx::query_as::<_, models::WithdrawalVote>(
+            r#"
+            INSERT INTO withdrawal_votes (
+                request_id, bucky_bank_id, guardian, approve, reason,
+                approvals, rejections, approval_threshold, created_at_ms
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(&vote.request_id)
+        .bind(&vote.bucky_bank_id)
+        .bind(&vote.guardian)
+        .bind(vote.approve)
+        .bind(&vote.reason)
+        .bind(vote.approvals)
+        .bind(vote.rejections)
+        .bind(vote.approval_threshold)
+        .bind(vote.created_at_ms)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    // 根据request_id查询投票记录
+    pub async fn get_withdrawal_votes_by_request_id(
+        &self,
+        request_id: &str,
+    ) -> Result<Vec<models::WithdrawalVote>> {
+        let result = sqlx::query_as::<_, models::WithdrawalVote>(
+            "SELECT * FROM withdrawal_votes WHERE request_id = $1 ORDER BY created_at_ms ASC"
+        )
+        .bind(request_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
     // 获取提取请求统计信息
     pub async fn get_withdrawal_requests_stats(
         &self,
@@ -754,8 +1853,9 @@ impl Database {
         let withdrawed_result = sqlx::query_as::<_, models::EventWithdrawedEvent>(
             r#"
             INSERT INTO withdrawed_events (
-                request_id, bucky_bank_id, amount, left_balance, withdrawer, created_at_ms
-            ) VALUES ($1, $2, $3, $4, $5, $6)
+                request_id, bucky_bank_id, amount, left_balance, withdrawer, created_at_ms,
+                mint, decimals, penalty_amount, penalty_recipient
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             RETURNING *
             "#,
         )
@@ -765,6 +1865,10 @@ impl Database {
         .bind(event.left_balance)
         .bind(&event.withdrawer)
         .bind(event.created_at_ms)
+        .bind(&event.mint)
+        .bind(event.decimals)
+        .bind(event.penalty_amount)
+        .bind(&event.penalty_recipient)
         .fetch_one(&mut *transaction)
         .await?;
 
@@ -814,6 +1918,7 @@ impl Database {
         Ok(withdrawed_result)
     }
 
+    #[allow(dead_code)]
     pub async fn save_withdrawed_events_batch(
         &self,
         events: &[models::NewEventWithdrawedEvent],
@@ -826,8 +1931,9 @@ impl Database {
             sqlx::query(
                 r#"
                 INSERT INTO withdrawed_events (
-                    request_id, bucky_bank_id, amount, left_balance, withdrawer, created_at_ms
-                ) VALUES ($1, $2, $3, $4, $5, $6)
+                    request_id, bucky_bank_id, amount, left_balance, withdrawer, created_at_ms,
+                    mint, decimals, penalty_amount, penalty_recipient
+                ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
                 ON CONFLICT DO NOTHING
                 "#,
             )
@@ -837,6 +1943,10 @@ impl Database {
             .bind(event.left_balance)
             .bind(&event.withdrawer)
             .bind(event.created_at_ms)
+            .bind(&event.mint)
+            .bind(event.decimals)
+            .bind(event.penalty_amount)
+            .bind(&event.penalty_recipient)
             .execute(&mut *transaction)
             .await?;
 
@@ -982,4 +2092,67 @@ impl Database {
 
         Ok(stats)
     }
+
+    // RewardClaimed 事件相关方法
+    pub async fn save_reward_claimed_event(
+        &self,
+        event: &models::NewRewardClaimedEvent,
+    ) -> Result<models::RewardClaimedEvent> {
+        let mut transaction = self.pool.begin().await?;
+
+        // 1. 插入奖励领取事件
+        let claimed_result = sqlx::query_as::<_, models::RewardClaimedEvent>(
+            r#"
+            INSERT INTO reward_claimed_events (
+                bucky_bank_id, child, amount, created_at_ms, mint, decimals
+            ) VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(&event.bucky_bank_id)
+        .bind(&event.child)
+        .bind(event.amount)
+        .bind(event.created_at_ms)
+        .bind(&event.mint)
+        .bind(event.decimals)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        // 2. 标记BuckyBank奖励已领取
+        let update_result = sqlx::query(
+            r#"
+            UPDATE bucky_bank_created_events
+            SET reward_claimed = TRUE
+            WHERE bucky_bank_id = $1
+            "#,
+        )
+        .bind(&event.bucky_bank_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        if update_result.rows_affected() == 0 {
+            return Err(anyhow::anyhow!(
+                "BuckyBank with id {} not found",
+                event.bucky_bank_id
+            ));
+        }
+
+        transaction.commit().await?;
+        Ok(claimed_result)
+    }
+
+    // 根据bucky_bank_id查询奖励领取事件
+    pub async fn get_reward_claimed_by_bank_id(
+        &self,
+        bucky_bank_id: &str,
+    ) -> Result<Option<models::RewardClaimedEvent>> {
+        let result = sqlx::query_as::<_, models::RewardClaimedEvent>(
+            "SELECT * FROM reward_claimed_events WHERE bucky_bank_id = $1"
+        )
+        .bind(bucky_bank_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
 }
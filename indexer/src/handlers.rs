@@ -1,4 +1,7 @@
-use crate::database::models::{WithdrawalRequestEvent, EventWithdrawedEvent, WithdrawalStatus};
+use crate::database::models::{
+    AchievementUnlocked, BankEmergencyAction, BuckyBankConfigChange, DepositContributor, DepositMadeEvent, EventWithdrawedEvent, GlobalStats, InterestAccrual, MatchCredit, MilestoneReached, RewardClaimedEvent, WithdrawalRequestEvent,
+    WithdrawalStatus, WithdrawalVote,
+};
 use crate::health::HealthState;
 use axum::{
     extract::{Path, Query, State},
@@ -55,6 +58,12 @@ pub struct WithdrawalRequestResponse {
     pub approved_by: Option<String>,
     pub created_at_ms: i64,
     pub audit_at_ms: Option<i64>,        // 审批时间
+    pub mint: Option<String>,
+    pub decimals: i16,
+    pub auto_approved: bool,
+    pub expires_at_ms: Option<i64>,
+    pub approved_amount: Option<i64>,
+    pub emergency: bool,
     pub indexed_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
@@ -71,6 +80,12 @@ impl From<WithdrawalRequestEvent> for WithdrawalRequestResponse {
             approved_by: event.approved_by,
             created_at_ms: event.created_at_ms,
             audit_at_ms: event.audit_at_ms, // 添加审批时间
+            mint: event.mint,
+            decimals: event.decimals,
+            auto_approved: event.auto_approved,
+            expires_at_ms: event.expires_at_ms,
+            approved_amount: event.approved_amount,
+            emergency: event.emergency,
             indexed_at: event.indexed_at,
         }
     }
@@ -85,7 +100,11 @@ pub struct EventWithdrawedResponse {
     pub left_balance: i64,
     pub withdrawer: String,
     pub created_at_ms: i64,
+    pub mint: Option<String>,
+    pub decimals: i16,
     pub created_at: chrono::DateTime<chrono::Utc>,
+    pub penalty_amount: i64,
+    pub penalty_recipient: Option<String>,
 }
 
 impl From<EventWithdrawedEvent> for EventWithdrawedResponse {
@@ -98,11 +117,86 @@ impl From<EventWithdrawedEvent> for EventWithdrawedResponse {
             left_balance: event.left_balance,
             withdrawer: event.withdrawer,
             created_at_ms: event.created_at_ms,
+            mint: event.mint,
+            decimals: event.decimals,
+            created_at: event.created_at,
+            penalty_amount: event.penalty_amount,
+            penalty_recipient: event.penalty_recipient,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DepositResponse {
+    pub id: uuid::Uuid,
+    pub bucky_bank_id: String,
+    pub amount: i64,
+    pub depositor: String,
+    pub message: Option<String>,
+    pub created_at_ms: i64,
+    pub mint: Option<String>,
+    pub decimals: i16,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<DepositMadeEvent> for DepositResponse {
+    fn from(event: DepositMadeEvent) -> Self {
+        Self {
+            id: event.id,
+            bucky_bank_id: event.bucky_bank_id,
+            amount: event.amount,
+            depositor: event.depositor,
+            message: event.message,
+            created_at_ms: event.created_at_ms,
+            mint: event.mint,
+            decimals: event.decimals,
+            created_at: event.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RewardClaimedResponse {
+    pub id: uuid::Uuid,
+    pub bucky_bank_id: String,
+    pub child: String,
+    pub amount: i64,
+    pub created_at_ms: i64,
+    pub mint: Option<String>,
+    pub decimals: i16,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<RewardClaimedEvent> for RewardClaimedResponse {
+    fn from(event: RewardClaimedEvent) -> Self {
+        Self {
+            id: event.id,
+            bucky_bank_id: event.bucky_bank_id,
+            child: event.child,
+            amount: event.amount,
+            created_at_ms: event.created_at_ms,
+            mint: event.mint,
+            decimals: event.decimals,
             created_at: event.created_at,
         }
     }
 }
 
+// 根据request_id获取监护人投票记录
+pub async fn get_withdrawal_votes_by_request_id(
+    State(state): State<HealthState>,
+    Path(request_id): Path<String>,
+) -> Result<Json<ApiResponse<Vec<WithdrawalVote>>>, StatusCode> {
+    let db = &state.db;
+    match db.get_withdrawal_votes_by_request_id(&request_id).await {
+        Ok(votes) => Ok(Json(ApiResponse::success(votes))),
+        Err(e) => {
+            tracing::error!("Failed to get withdrawal votes: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
 // 根据request_id获取单个提取请求
 pub async fn get_withdrawal_request_by_id(
     State(state): State<HealthState>,
@@ -185,7 +279,7 @@ pub async fn get_withdrawal_requests_by_status(
         Ok(s) => s,
         Err(_) => {
             return Ok(Json(ApiResponse::error(format!(
-                "Invalid status: {}. Valid values are: Pending, Approved, Rejected, Cancelled, Withdrawed",
+                "Invalid status: {}. Valid values are: Pending, Approved, Rejected, Cancelled, Withdrawed, Expired",
                 status_str
             ))));
         }
@@ -243,7 +337,7 @@ pub async fn update_withdrawal_request_status(
         Ok(s) => s,
         Err(_) => {
             return Ok(Json(ApiResponse::error(format!(
-                "Invalid status: {}. Valid values are: Pending, Approved, Rejected, Cancelled, Withdrawed",
+                "Invalid status: {}. Valid values are: Pending, Approved, Rejected, Cancelled, Withdrawed, Expired",
                 payload.status
             ))));
         }
@@ -348,4 +442,207 @@ pub async fn get_event_withdrawed_stats(
             Err(StatusCode::INTERNAL_SERVER_ERROR)
         }
     }
-}
\ No newline at end of file
+}
+
+// RewardClaimed 事件相关API
+
+// 根据bucky_bank_id获取奖励领取事件
+pub async fn get_reward_claimed_by_bank_id(
+    State(state): State<HealthState>,
+    Path(bucky_bank_id): Path<String>,
+) -> Result<Json<ApiResponse<RewardClaimedResponse>>, StatusCode> {
+    let db = &state.db;
+    match db.get_reward_claimed_by_bank_id(&bucky_bank_id).await {
+        Ok(Some(event)) => {
+            let response = RewardClaimedResponse::from(event);
+            Ok(Json(ApiResponse::success(response)))
+        }
+        Ok(None) => Err(StatusCode::NOT_FOUND),
+        Err(e) => {
+            tracing::error!("Failed to get reward claimed event: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+// DepositMade 事件相关API
+
+// 根据bucky_bank_id获取存款记录（含留言）
+pub async fn get_deposits_by_bank_id(
+    State(state): State<HealthState>,
+    Path(bucky_bank_id): Path<String>,
+    Query(params): Query<PaginationParams>,
+) -> Result<Json<ApiResponse<Vec<DepositResponse>>>, StatusCode> {
+    let db = &state.db;
+    match db
+        .get_deposits_by_bank_id(&bucky_bank_id, params.limit, params.offset)
+        .await
+    {
+        Ok(events) => {
+            let responses: Vec<DepositResponse> = events
+                .into_iter()
+                .map(DepositResponse::from)
+                .collect();
+            Ok(Json(ApiResponse::success(responses)))
+        }
+        Err(e) => {
+            tracing::error!("Failed to get deposits by bank id: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+// 根据bucky_bank_id获取按存款人汇总的贡献
+pub async fn get_contributors_by_bank_id(
+    State(state): State<HealthState>,
+    Path(bucky_bank_id): Path<String>,
+) -> Result<Json<ApiResponse<Vec<DepositContributor>>>, StatusCode> {
+    let db = &state.db;
+    match db.get_contributors_by_bank_id(&bucky_bank_id).await {
+        Ok(contributors) => Ok(Json(ApiResponse::success(contributors))),
+        Err(e) => {
+            tracing::error!("Failed to get contributors by bank id: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+// BuckyBankConfigUpdated 事件相关API
+
+// 根据bucky_bank_id获取配置变更历史
+pub async fn get_config_changes_by_bank_id(
+    State(state): State<HealthState>,
+    Path(bucky_bank_id): Path<String>,
+) -> Result<Json<ApiResponse<Vec<BuckyBankConfigChange>>>, StatusCode> {
+    let db = &state.db;
+    match db.get_config_changes_by_bank_id(&bucky_bank_id).await {
+        Ok(changes) => Ok(Json(ApiResponse::success(changes))),
+        Err(e) => {
+            tracing::error!("Failed to get config changes by bank id: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+pub async fn get_global_stats(
+    State(state): State<HealthState>,
+) -> Result<Json<ApiResponse<GlobalStats>>, StatusCode> {
+    let db = &state.db;
+    match db.get_global_stats().await {
+        Ok(Some(stats)) => Ok(Json(ApiResponse::success(stats))),
+        Ok(None) => Err(StatusCode::NOT_FOUND),
+        Err(e) => {
+            tracing::error!("Failed to get global stats: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+// 根据bucky_bank_id获取家长配捐记录
+pub async fn get_match_credits_by_bank_id(
+    State(state): State<HealthState>,
+    Path(bucky_bank_id): Path<String>,
+    Query(params): Query<PaginationParams>,
+) -> Result<Json<ApiResponse<Vec<MatchCredit>>>, StatusCode> {
+    let db = &state.db;
+    match db
+        .get_match_credits_by_bank_id(&bucky_bank_id, params.limit, params.offset)
+        .await
+    {
+        Ok(credits) => Ok(Json(ApiResponse::success(credits))),
+        Err(e) => {
+            tracing::error!("Failed to get match credits by bank id: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+// 根据bucky_bank_id获取计息记录
+pub async fn get_interest_accruals_by_bank_id(
+    State(state): State<HealthState>,
+    Path(bucky_bank_id): Path<String>,
+    Query(params): Query<PaginationParams>,
+) -> Result<Json<ApiResponse<Vec<InterestAccrual>>>, StatusCode> {
+    let db = &state.db;
+    match db
+        .get_interest_accruals_by_bank_id(&bucky_bank_id, params.limit, params.offset)
+        .await
+    {
+        Ok(accruals) => Ok(Json(ApiResponse::success(accruals))),
+        Err(e) => {
+            tracing::error!("Failed to get interest accruals by bank id: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+// 根据bucky_bank_id获取家长紧急操作记录
+pub async fn get_emergency_actions_by_bank_id(
+    State(state): State<HealthState>,
+    Path(bucky_bank_id): Path<String>,
+    Query(params): Query<PaginationParams>,
+) -> Result<Json<ApiResponse<Vec<BankEmergencyAction>>>, StatusCode> {
+    let db = &state.db;
+    match db
+        .get_emergency_actions_by_bank_id(&bucky_bank_id, params.limit, params.offset)
+        .await
+    {
+        Ok(actions) => Ok(Json(ApiResponse::success(actions))),
+        Err(e) => {
+            tracing::error!("Failed to get emergency actions by bank id: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+// 获取所有存钱罐最近的家长紧急操作记录
+pub async fn get_recent_emergency_actions(
+    State(state): State<HealthState>,
+    Query(params): Query<PaginationParams>,
+) -> Result<Json<ApiResponse<Vec<BankEmergencyAction>>>, StatusCode> {
+    let db = &state.db;
+    match db
+        .get_recent_emergency_actions(params.limit, params.offset)
+        .await
+    {
+        Ok(actions) => Ok(Json(ApiResponse::success(actions))),
+        Err(e) => {
+            tracing::error!("Failed to get recent emergency actions: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+// 根据bucky_bank_id获取里程碑达成记录
+pub async fn get_milestones_by_bank_id(
+    State(state): State<HealthState>,
+    Path(bucky_bank_id): Path<String>,
+    Query(params): Query<PaginationParams>,
+) -> Result<Json<ApiResponse<Vec<MilestoneReached>>>, StatusCode> {
+    let db = &state.db;
+    match db
+        .get_milestones_reached_by_bank_id(&bucky_bank_id, params.limit, params.offset)
+        .await
+    {
+        Ok(milestones) => Ok(Json(ApiResponse::success(milestones))),
+        Err(e) => {
+            tracing::error!("Failed to get milestones by bank id: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+// 根据孩子地址获取已解锁的成就徽章
+pub async fn get_achievements_by_child(
+    State(state): State<HealthState>,
+    Path(child): Path<String>,
+) -> Result<Json<ApiResponse<Vec<AchievementUnlocked>>>, StatusCode> {
+    let db = &state.db;
+    match db.get_achievements_by_child(&child).await {
+        Ok(achievements) => Ok(Json(ApiResponse::success(achievements))),
+        Err(e) => {
+            tracing::error!("Failed to get achievements by child: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
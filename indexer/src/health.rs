@@ -36,11 +36,31 @@ pub fn health_routes(state: HealthState) -> Router {
         .route("/api/withdrawal-requests/status", get(handlers::get_withdrawal_requests_by_status))
         .route("/api/withdrawal-requests/stats", get(handlers::get_withdrawal_requests_stats))
         .route("/api/withdrawal-requests/:request_id/status", put(handlers::update_withdrawal_request_status))
+        .route("/api/withdrawal-requests/:request_id/votes", get(handlers::get_withdrawal_votes_by_request_id))
         // EventWithdrawed 事件相关API
         .route("/api/event-withdrawed/:request_id", get(handlers::get_event_withdrawed_by_request_id))
         .route("/api/event-withdrawed/bank/:bucky_bank_id", get(handlers::get_event_withdrawed_by_bank_id))
         .route("/api/event-withdrawed/withdrawer/:withdrawer", get(handlers::get_event_withdrawed_by_withdrawer))
         .route("/api/event-withdrawed/stats", get(handlers::get_event_withdrawed_stats))
+        // DepositMade 事件相关API
+        .route("/api/deposits/bank/:bucky_bank_id", get(handlers::get_deposits_by_bank_id))
+        .route("/api/deposits/bank/:bucky_bank_id/contributors", get(handlers::get_contributors_by_bank_id))
+        .route("/api/match-credits/bank/:bucky_bank_id", get(handlers::get_match_credits_by_bank_id))
+        // MilestoneReached 事件相关API
+        .route("/api/milestones/bank/:bucky_bank_id", get(handlers::get_milestones_by_bank_id))
+        // AchievementUnlocked 事件相关API
+        .route("/api/achievements/child/:child", get(handlers::get_achievements_by_child))
+        // InterestAccrued 事件相关API
+        .route("/api/interest/bank/:bucky_bank_id", get(handlers::get_interest_accruals_by_bank_id))
+        // 家长紧急操作（冻结、解冻、紧急取款）相关API
+        .route("/api/emergency-actions/recent", get(handlers::get_recent_emergency_actions))
+        .route("/api/emergency-actions/bank/:bucky_bank_id", get(handlers::get_emergency_actions_by_bank_id))
+        // BuckyBankConfigUpdated 事件相关API
+        .route("/api/config-changes/bank/:bucky_bank_id", get(handlers::get_config_changes_by_bank_id))
+        // RewardClaimed 事件相关API
+        .route("/api/reward-claimed/bank/:bucky_bank_id", get(handlers::get_reward_claimed_by_bank_id))
+        // 全局统计API
+        .route("/api/global-stats", get(handlers::get_global_stats))
         .with_state(state)
 }
 
@@ -1,6 +1,9 @@
 use crate::database::{
     Database,
-    models::{NewBuckyBankCreatedEvent, NewDepositMadeEvent, NewWithdrawalRequestEvent, NewEventWithdrawedEvent, NewCursor, WithdrawalStatus},
+    models::{
+        NewBuckyBankCreatedEvent, NewDepositMadeEvent, NewWithdrawalRequestEvent, NewEventWithdrawedEvent,
+        NewRewardClaimedEvent, NewWithdrawalVote, NewBuckyBankConfigChange, NewCursor, NewMatchCredit, NewInterestAccrual, NewMilestoneReached, NewAchievementUnlocked, NewBankEmergencyAction, GlobalStats, WithdrawalStatus,
+    },
 };
 use anyhow::Result;
 use std::sync::Arc;
@@ -8,10 +11,22 @@ use solana_client::rpc_client::{RpcClient, GetConfirmedSignaturesForAddress2Conf
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use solana_transaction_status::UiTransactionEncoding;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, trace, warn};
 use base64::Engine;
 use borsh::BorshDeserialize;
 
+const BUCKY_BANK_SEED: &[u8] = b"bucky_bank";
+
+/// 推导存钱罐地址，与链上 `BuckyBankInfo::derive_address` 一致：
+/// 种子为 `[b"bucky_bank", 家长地址, nonce 小端 8 字节]`
+pub fn derive_bucky_bank_address(program_id: &Pubkey, parent: &Pubkey, bank_nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[BUCKY_BANK_SEED, parent.as_ref(), &bank_nonce.to_le_bytes()],
+        program_id,
+    )
+    .0
+}
+
 // Anchor 事件结构体定义（用于 Borsh 反序列化）
 #[derive(Debug, BorshDeserialize)]
 pub struct BuckyBankCreatedEvent {
@@ -24,6 +39,10 @@ pub struct BuckyBankCreatedEvent {
     pub deadline_ms: u64,
     pub duration_days: u64,
     pub current_balance: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+    pub reward_amount: u64,
+    pub bank_nonce: u64,
 }
 
 #[derive(Debug, BorshDeserialize)]
@@ -32,6 +51,9 @@ pub struct DepositMadeEvent {
     pub amount: u64,
     pub depositor: Pubkey,
     pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+    pub message: Option<String>,
 }
 
 #[derive(Debug, BorshDeserialize)]
@@ -44,19 +66,53 @@ pub struct EventWithdrawalRequestedEvent {
     pub status: u8,
     pub approved_by: Pubkey,
     pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+    pub auto_approved: bool,
+    pub expires_at_ms: u64,
+    pub emergency: bool,
 }
 
+// 事件结构体的 Borsh 布局需与链上事件一致，未读取的字段也要保留
+#[allow(dead_code)]
 #[derive(Debug, BorshDeserialize)]
-pub struct EventWithdrawalApprovedEvent {
+pub struct WithdrawalRequestExpiredEvent {
     pub request_id: Pubkey,
     pub bucky_bank_id: Pubkey,
     pub amount: u64,
+    pub requester: Pubkey,
+    pub expires_at_ms: u64,
+    pub expired_by: Pubkey,
+    pub created_at_ms: u64,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, BorshDeserialize)]
+pub struct WithdrawalAutoApprovedEvent {
+    pub request_id: Pubkey,
+    pub bucky_bank_id: Pubkey,
+    pub amount: u64,
+    pub requester: Pubkey,
+    pub auto_approve_limit: u64,
+    pub daily_spent: u64,
+    pub weekly_spent: u64,
+    pub created_at_ms: u64,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, BorshDeserialize)]
+pub struct EventWithdrawalApprovedEvent {
+    pub request_id: Pubkey,
+    pub bucky_bank_id: Pubkey,
+    pub requested_amount: u64,
+    pub approved_amount: u64,
     pub approved_by: Pubkey,
     pub requester: Pubkey,
     pub reason: String,
     pub created_at_ms: u64,
 }
 
+#[allow(dead_code)]
 #[derive(Debug, BorshDeserialize)]
 pub struct EventWithdrawalRejectedEvent {
     pub request_id: Pubkey,
@@ -69,14 +125,284 @@ pub struct EventWithdrawalRejectedEvent {
 }
 
 #[derive(Debug, BorshDeserialize)]
-pub struct EventWithdrawalCompletedEvent {
+pub struct WithdrawalVoteCastEvent {
+    pub request_id: Pubkey,
+    pub bucky_bank_id: Pubkey,
+    pub guardian: Pubkey,
+    pub approve: bool,
+    pub reason: String,
+    pub approvals: u8,
+    pub rejections: u8,
+    pub approval_threshold: u8,
+    pub created_at_ms: u64,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, BorshDeserialize)]
+pub struct EventWithdrawalCancelledEvent {
     pub request_id: Pubkey,
     pub bucky_bank_id: Pubkey,
     pub amount: u64,
     pub requester: Pubkey,
+    pub previous_status: u8,
+    pub created_at_ms: u64,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct EventWithdrawalCompletedEvent {
+    pub request_id: Pubkey,
+    pub bucky_bank_id: Pubkey,
+    pub amount: u64,
+    pub left_balance: u64,
+    pub withdrawer: Pubkey,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+    pub penalty_amount: u64,
+    pub penalty_recipient: Option<Pubkey>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, BorshDeserialize)]
+pub struct BalanceReconciledEvent {
+    pub bucky_bank_id: Pubkey,
+    pub previous_balance: u64,
+    pub current_balance: u64,
+    pub synced_by: Pubkey,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+/// 存钱罐状态，与合约 `BuckyBankStatus` 的 Borsh 编码一致
+#[derive(Debug, Clone, Copy, BorshDeserialize)]
+pub enum BuckyBankStatus {
+    Active,
+    Completed,
+    Failed,
+}
+
+impl BuckyBankStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BuckyBankStatus::Active => "Active",
+            BuckyBankStatus::Completed => "Completed",
+            BuckyBankStatus::Failed => "Failed",
+        }
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, BorshDeserialize)]
+pub struct BuckyBankSettledEvent {
+    pub bucky_bank_id: Pubkey,
+    pub status: BuckyBankStatus,
+    pub current_balance: u64,
+    pub target_amount: u64,
+    pub deadline_ms: u64,
+    pub settled_by: Pubkey,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, BorshDeserialize)]
+pub struct BuckyBankClosedEvent {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub status: BuckyBankStatus,
+    pub recipient: Pubkey,
+    pub payout_amount: u64,
+    pub reward_payout_amount: u64,
+    pub match_refund_amount: u64,
+    pub interest_refund_amount: u64,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct BuckyBankConfigUpdatedEvent {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub old_name: String,
+    pub new_name: String,
+    pub old_target_amount: u64,
+    pub new_target_amount: u64,
+    pub old_deadline_ms: u64,
+    pub new_deadline_ms: u64,
+    pub old_status: BuckyBankStatus,
+    pub new_status: BuckyBankStatus,
+    pub created_at_ms: u64,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, BorshDeserialize)]
+pub struct ParentTransferredEvent {
+    pub bucky_bank_id: Pubkey,
+    pub old_parent: Pubkey,
+    pub new_parent: Pubkey,
+    pub created_at_ms: u64,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, BorshDeserialize)]
+pub struct ChildRotatedEvent {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub old_child: Pubkey,
+    pub new_child: Pubkey,
+    pub created_at_ms: u64,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct MatchCreditedEvent {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub depositor: Pubkey,
+    pub deposit_amount: u64,
+    pub match_amount: u64,
+    pub credited_amount: u64,
+    pub skipped: bool,
+    pub match_vault_balance: u64,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct InterestAccruedEvent {
+    pub bucky_bank_id: Pubkey,
+    pub interest_rate_bps: u16,
+    pub current_balance: u64,
+    pub interest_amount: u64,
+    pub credited_amount: u64,
+    pub skipped: bool,
+    pub interest_pending: u64,
+    pub interest_vault_balance: u64,
+    pub total_interest_earned: u64,
+    pub accrued_by: Pubkey,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct MilestoneReachedEvent {
+    pub bucky_bank_id: Pubkey,
+    pub milestone_index: u8,
+    pub amount: u64,
+    pub label: String,
+    pub current_balance: u64,
+    pub target_amount: u64,
+    pub reached_by: Pubkey,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+/// 成就徽章，与合约 `AchievementBadge` 的 Borsh 编码一致
+#[derive(Debug, Clone, Copy, BorshDeserialize)]
+pub enum AchievementBadge {
+    FirstDeposit,
+    WeeklyStreak,
+    HalfwayToTarget,
+    GoalCompleted,
+}
+
+impl AchievementBadge {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AchievementBadge::FirstDeposit => "FirstDeposit",
+            AchievementBadge::WeeklyStreak => "WeeklyStreak",
+            AchievementBadge::HalfwayToTarget => "HalfwayToTarget",
+            AchievementBadge::GoalCompleted => "GoalCompleted",
+        }
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct AchievementUnlockedEvent {
+    pub child: Pubkey,
+    pub bucky_bank_id: Pubkey,
+    pub badge: AchievementBadge,
+    pub current_streak_weeks: u32,
+    pub longest_streak_weeks: u32,
+    pub current_balance: u64,
+    pub target_amount: u64,
     pub created_at_ms: u64,
 }
 
+#[derive(Debug, BorshDeserialize)]
+pub struct BuckyBankFrozenEvent {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub reason: String,
+    pub created_at_ms: u64,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct BuckyBankUnfrozenEvent {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub reason: String,
+    pub created_at_ms: u64,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct EmergencyWithdrawalEvent {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub left_balance: u64,
+    pub reason: String,
+    pub frozen: bool,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct GlobalStatsUpdatedEvent {
+    pub bucky_bank_id: Pubkey,
+    pub total_bucky_banks: i64,
+    pub active_bucky_banks: i64,
+    pub completed_bucky_banks: i64,
+    pub failed_bucky_banks: i64,
+    pub closed_bucky_banks: i64,
+    pub total_deposits: i64,
+    pub total_deposit_amount: i64,
+    pub total_withdrawals: i64,
+    pub total_withdrawal_amount: i64,
+    pub total_value_locked: i64,
+    pub updated_at_ms: u64,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, BorshDeserialize)]
+pub struct SavingRewardUpdatedEvent {
+    pub bucky_bank_id: Pubkey,
+    pub parent: Pubkey,
+    pub old_reward_amount: u64,
+    pub new_reward_amount: u64,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[derive(Debug, BorshDeserialize)]
+pub struct RewardClaimedEvent {
+    pub bucky_bank_id: Pubkey,
+    pub child: Pubkey,
+    pub amount: u64,
+    pub created_at_ms: u64,
+    pub mint: Option<Pubkey>,
+    pub decimals: u8,
+}
+
+#[allow(dead_code)]
 #[derive(Debug)]
 pub struct EventProcessingResult {
     pub total_processed: usize,
@@ -91,6 +417,26 @@ pub enum EventType {
     WithdrawalApproved,
     WithdrawalRejected,
     EventWithdrawed,
+    BalanceReconciled,
+    SavingRewardUpdated,
+    RewardClaimed,
+    BuckyBankSettled,
+    WithdrawalCancelled,
+    BuckyBankClosed,
+    WithdrawalVoteCast,
+    WithdrawalAutoApproved,
+    BuckyBankConfigUpdated,
+    ParentTransferred,
+    ChildRotated,
+    MatchCredited,
+    InterestAccrued,
+    MilestoneReached,
+    AchievementUnlocked,
+    WithdrawalRequestExpired,
+    BuckyBankFrozen,
+    BuckyBankUnfrozen,
+    EmergencyWithdrawal,
+    GlobalStatsUpdated,
 }
 
 impl EventType {
@@ -102,9 +448,93 @@ impl EventType {
             EventType::WithdrawalApproved => "EventWithdrawalApproved",
             EventType::WithdrawalRejected => "EventWithdrawalRejected",
             EventType::EventWithdrawed => "EventWithdrawalCompleted",
+            EventType::BalanceReconciled => "BalanceReconciled",
+            EventType::SavingRewardUpdated => "SavingRewardUpdated",
+            EventType::RewardClaimed => "RewardClaimed",
+            EventType::BuckyBankSettled => "BuckyBankSettled",
+            EventType::WithdrawalCancelled => "EventWithdrawalCancelled",
+            EventType::BuckyBankClosed => "BuckyBankClosed",
+            EventType::WithdrawalVoteCast => "WithdrawalVoteCast",
+            EventType::WithdrawalAutoApproved => "WithdrawalAutoApproved",
+            EventType::BuckyBankConfigUpdated => "BuckyBankConfigUpdated",
+            EventType::ParentTransferred => "ParentTransferred",
+            EventType::ChildRotated => "ChildRotated",
+            EventType::MatchCredited => "MatchCredited",
+            EventType::InterestAccrued => "InterestAccrued",
+            EventType::MilestoneReached => "MilestoneReached",
+            EventType::AchievementUnlocked => "AchievementUnlocked",
+            EventType::WithdrawalRequestExpired => "WithdrawalRequestExpired",
+            EventType::BuckyBankFrozen => "BuckyBankFrozen",
+            EventType::BuckyBankUnfrozen => "BuckyBankUnfrozen",
+            EventType::EmergencyWithdrawal => "EmergencyWithdrawal",
+            EventType::GlobalStatsUpdated => "GlobalStatsUpdated",
         }
     }
 
+    /// Anchor 事件判别符：sha256("event:<事件名>") 的前 8 字节
+    pub fn discriminator(&self) -> [u8; 8] {
+        let preimage = format!("event:{}", self.name());
+        let hash = anchor_lang::solana_program::hash::hash(preimage.as_bytes());
+        let mut discriminator = [0u8; 8];
+        discriminator.copy_from_slice(&hash.to_bytes()[..8]);
+        discriminator
+    }
+
+    /// 判断 base64 编码的 Program data 是否为该类型的事件
+    pub fn matches_program_data(&self, base64_data: &str) -> bool {
+        base64::engine::general_purpose::STANDARD
+            .decode(base64_data)
+            .map(|bytes| bytes.len() >= 8 && bytes[..8] == self.discriminator())
+            .unwrap_or(false)
+    }
+
+    /// 判断日志行是否为该类型事件对应的指令日志
+    pub fn matches_log(&self, log: &str) -> bool {
+        // Solana 事件通过日志中的特定前缀来识别
+        // 格式通常是: "Program log: Instruction: <instruction_name>" 或 "Program log: <event_name>: <data>"
+        let patterns = match self {
+            EventType::BuckyBankCreated => vec!["Instruction: CreateBuckyBank", "BuckyBankCreated"],
+            EventType::DepositMade => vec!["Instruction: Deposit", "DepositMade"],
+            EventType::WithdrawalRequested => vec!["Instruction: RequestWithdrawal", "WithdrawalRequested", "EventWithdrawalRequested"],
+            EventType::WithdrawalApproved => vec!["Instruction: ApproveWithdrawal", "WithdrawalApproved", "EventWithdrawalApproved"],
+            EventType::WithdrawalRejected => vec!["Instruction: RejectWithdrawal", "WithdrawalRejected", "EventWithdrawalRejected"],
+            EventType::EventWithdrawed => vec!["Instruction: Withdraw", "EventWithdrawalCompleted", "EventWithdrawed"],
+            EventType::BalanceReconciled => vec!["Instruction: SyncBalance", "BalanceReconciled"],
+            EventType::SavingRewardUpdated => vec!["Instruction: SetSavingReward", "SavingRewardUpdated"],
+            EventType::RewardClaimed => vec!["Instruction: ClaimSavingRewards", "RewardClaimed"],
+            EventType::BuckyBankSettled => vec!["Instruction: SettleBuckyBank", "BuckyBankSettled"],
+            EventType::WithdrawalCancelled => vec!["Instruction: CancelWithdrawal", "Instruction: RotateChild", "EventWithdrawalCancelled"],
+            EventType::BuckyBankClosed => vec!["Instruction: CloseBuckyBank", "Instruction: CloseLegacyBuckyBank", "BuckyBankClosed"],
+            EventType::WithdrawalVoteCast => vec!["Instruction: ApproveWithdrawal", "WithdrawalVoteCast"],
+            EventType::WithdrawalAutoApproved => vec!["Instruction: RequestWithdrawal", "WithdrawalAutoApproved"],
+            EventType::BuckyBankConfigUpdated => vec!["Instruction: UpdateBuckyBankConfig", "BuckyBankConfigUpdated"],
+            EventType::ParentTransferred => vec!["Instruction: AcceptParentTransfer", "ParentTransferred"],
+            EventType::ChildRotated => vec!["Instruction: RotateChild", "ChildRotated"],
+            EventType::MatchCredited => vec!["Instruction: Deposit", "MatchCredited"],
+            EventType::InterestAccrued => vec!["Instruction: AccrueInterest", "InterestAccrued"],
+            EventType::MilestoneReached => vec!["Instruction: Deposit", "Instruction: SetMilestones", "MilestoneReached"],
+            EventType::AchievementUnlocked => vec!["Instruction: Deposit", "AchievementUnlocked"],
+            EventType::WithdrawalRequestExpired => vec!["Instruction: ExpireWithdrawalRequest", "WithdrawalRequestExpired"],
+            EventType::BuckyBankFrozen => vec!["Instruction: FreezeBuckyBank", "BuckyBankFrozen"],
+            EventType::BuckyBankUnfrozen => vec!["Instruction: UnfreezeBuckyBank", "BuckyBankUnfrozen"],
+            EventType::EmergencyWithdrawal => vec!["Instruction: EmergencyWithdraw", "EmergencyWithdrawal"],
+            EventType::GlobalStatsUpdated => vec![
+                "Instruction: CreateBuckyBank",
+                "Instruction: Deposit",
+                "Instruction: Withdraw",
+                "Instruction: EmergencyWithdraw",
+                "Instruction: AccrueInterest",
+                "Instruction: SyncBalance",
+                "Instruction: SettleBuckyBank",
+                "Instruction: UpdateBuckyBankConfig",
+                "Instruction: CloseBuckyBank",
+                "GlobalStatsUpdated",
+            ],
+        };
+
+        patterns.iter().any(|pattern| log.contains(pattern))
+    }
+
     pub fn all_event_types() -> Vec<EventType> {
         vec![
             EventType::BuckyBankCreated, 
@@ -113,6 +543,26 @@ impl EventType {
             EventType::WithdrawalApproved,
             EventType::WithdrawalRejected,
             EventType::EventWithdrawed,
+            EventType::BalanceReconciled,
+            EventType::SavingRewardUpdated,
+            EventType::RewardClaimed,
+            EventType::BuckyBankSettled,
+            EventType::WithdrawalCancelled,
+            EventType::BuckyBankClosed,
+            EventType::WithdrawalVoteCast,
+            EventType::WithdrawalAutoApproved,
+            EventType::BuckyBankConfigUpdated,
+            EventType::ParentTransferred,
+            EventType::ChildRotated,
+            EventType::MatchCredited,
+            EventType::InterestAccrued,
+            EventType::MilestoneReached,
+            EventType::AchievementUnlocked,
+            EventType::WithdrawalRequestExpired,
+            EventType::BuckyBankFrozen,
+            EventType::BuckyBankUnfrozen,
+            EventType::EmergencyWithdrawal,
+            EventType::GlobalStatsUpdated,
         ]
     }
 }
@@ -348,63 +798,25 @@ impl BuckyBankIndexer {
 
     async fn process_transaction_events(&self, log_messages: &[String], event_type: &EventType) -> Result<usize> {
         let mut events_processed = 0;
-        let mut i = 0;
         
-        info!("[DEBUG] === Processing {} log messages for {} ===", log_messages.len(), event_type.name());
+        debug!("Processing {} log messages for {}", log_messages.len(), event_type.name());
         for (idx, msg) in log_messages.iter().enumerate() {
-            info!("[DEBUG] Log[{}]: {}", idx, msg);
+            trace!("Log[{}]: {}", idx, msg);
         }
         
-        while i < log_messages.len() {
-            let log = &log_messages[i];
-            
-            // 检查是否是指令日志
-            if self.matches_event_type(log, event_type) {
-                info!("[DEBUG] ✓ Matched {} event in log: {}", event_type.name(), log);
-                
-                // 查找后续的 "Program data:" 行
-                let mut j = i + 1;
-                let mut found_data = false;
-                while j < log_messages.len() {
-                    let next_log = &log_messages[j];
-                    info!("[DEBUG] Checking log at index {}: {}", j, next_log);
-                    
-                    if next_log.starts_with("Program data:") {
-                        info!("[DEBUG] Found Program data at index {}: {}", j, next_log);
-                        found_data = true;
-                        
-                        // 提取 base64 数据
-                        if let Some(data_str) = next_log.strip_prefix("Program data: ") {
-                            match self.process_event_from_base64(data_str, event_type).await {
-                                Ok(_) => {
-                                    events_processed += 1;
-                                    info!("Successfully processed {} event from base64 data", event_type.name());
-                                }
-                                Err(e) => {
-                                    error!("Failed to process {} event from base64: {}", event_type.name(), e);
-                                }
-                            }
-                        }
-                        break;
-                    } else if next_log.starts_with("Program") && !next_log.starts_with("Program data:") {
-                        // 遇到其他程序日志，停止查找
-                        info!("[DEBUG] Encountered other program log, stopping search: {}", next_log);
-                        // 这个地方导致没有入库，不是搞啥呀
-                        // break;
-                    }
-                    
-                    j += 1;
+        for data_str in find_event_data(log_messages, event_type) {
+            match self.process_event_from_base64(data_str, event_type).await {
+                Ok(_) => {
+                    events_processed += 1;
+                    info!("Successfully processed {} event from base64 data", event_type.name());
                 }
-                
-                if !found_data {
-                    info!("[DEBUG] ⚠ No Program data found after matched event");
+                Err(e) => {
+                    error!("Failed to process {} event from base64: {}", event_type.name(), e);
                 }
             }
-            
-            i += 1;
         }
         
-        info!("[DEBUG] === Finished processing, events_processed: {} ===\n\n~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~\n\n", events_processed);
+        debug!("Finished processing {}, events_processed: {}", event_type.name(), events_processed);
         Ok(events_processed)
     }
 
@@ -423,33 +835,30 @@ impl BuckyBankIndexer {
             EventType::WithdrawalApproved => self.process_withdrawal_approved_from_bytes(&decoded).await,
             EventType::WithdrawalRejected => self.process_withdrawal_rejected_from_bytes(&decoded).await,
             EventType::EventWithdrawed => self.process_event_withdrawed_from_bytes(&decoded).await,
+            EventType::BalanceReconciled => self.process_balance_reconciled_from_bytes(&decoded).await,
+            EventType::SavingRewardUpdated => self.process_saving_reward_updated_from_bytes(&decoded).await,
+            EventType::RewardClaimed => self.process_reward_claimed_from_bytes(&decoded).await,
+            EventType::BuckyBankSettled => self.process_bucky_bank_settled_from_bytes(&decoded).await,
+            EventType::WithdrawalCancelled => self.process_withdrawal_cancelled_from_bytes(&decoded).await,
+            EventType::BuckyBankClosed => self.process_bucky_bank_closed_from_bytes(&decoded).await,
+            EventType::WithdrawalVoteCast => self.process_withdrawal_vote_cast_from_bytes(&decoded).await,
+            EventType::WithdrawalAutoApproved => self.process_withdrawal_auto_approved_from_bytes(&decoded).await,
+            EventType::BuckyBankConfigUpdated => self.process_bucky_bank_config_updated_from_bytes(&decoded).await,
+            EventType::ParentTransferred => self.process_parent_transferred_from_bytes(&decoded).await,
+            EventType::ChildRotated => self.process_child_rotated_from_bytes(&decoded).await,
+            EventType::MatchCredited => self.process_match_credited_from_bytes(&decoded).await,
+            EventType::InterestAccrued => self.process_interest_accrued_from_bytes(&decoded).await,
+            EventType::MilestoneReached => self.process_milestone_reached_from_bytes(&decoded).await,
+            EventType::AchievementUnlocked => self.process_achievement_unlocked_from_bytes(&decoded).await,
+            EventType::WithdrawalRequestExpired => self.process_withdrawal_request_expired_from_bytes(&decoded).await,
+            EventType::BuckyBankFrozen => self.process_bucky_bank_frozen_from_bytes(&decoded).await,
+            EventType::BuckyBankUnfrozen => self.process_bucky_bank_unfrozen_from_bytes(&decoded).await,
+            EventType::EmergencyWithdrawal => self.process_emergency_withdrawal_from_bytes(&decoded).await,
+            EventType::GlobalStatsUpdated => self.process_global_stats_updated_from_bytes(&decoded).await,
         }
     }
 
-    fn matches_event_type(&self, log: &str, event_type: &EventType) -> bool {
-        // Solana 事件通过日志中的特定前缀来识别
-        // 格式通常是: "Program log: Instruction: <instruction_name>" 或 "Program log: <event_name>: <data>"
-        let patterns = match event_type {
-            EventType::BuckyBankCreated => vec!["Instruction: CreateBuckyBank", "BuckyBankCreated"],
-            EventType::DepositMade => vec!["Instruction: Deposit", "DepositMade"],
-            EventType::WithdrawalRequested => vec!["Instruction: RequestWithdrawal", "WithdrawalRequested", "EventWithdrawalRequested"],
-            EventType::WithdrawalApproved => vec!["Instruction: ApproveWithdrawal", "WithdrawalApproved", "EventWithdrawalApproved"],
-            EventType::WithdrawalRejected => vec!["Instruction: RejectWithdrawal", "WithdrawalRejected", "EventWithdrawalRejected"],
-            EventType::EventWithdrawed => vec!["Instruction: Withdraw", "EventWithdrawalCompleted", "EventWithdrawed"],
-        };
-        
-        let matches = patterns.iter().any(|pattern| log.contains(pattern));
-        
-        if !matches {
-            debug!("[DEBUG] Log does NOT match {:?} - checking patterns: {:?} in log: {}", 
-                event_type, patterns, log);
-        } else {
-            info!("[DEBUG] ✓ Matched {:?} with log: {}", event_type, log);
-        }
-        
-        matches
-    }
-
+    #[allow(dead_code)]
     async fn process_event_from_log(&self, log: &str, event_type: &EventType) -> Result<()> {
         // 从日志中提取事件数据
         // 这里需要根据实际的日志格式进行解析
@@ -460,9 +869,15 @@ impl BuckyBankIndexer {
             EventType::WithdrawalApproved => self.process_withdrawal_approved_event(log).await,
             EventType::WithdrawalRejected => self.process_withdrawal_rejected_event(log).await,
             EventType::EventWithdrawed => self.process_event_withdrawed_event(log).await,
+            // 其余事件仅支持从 Program data 解析
+            _ => {
+                debug!("Skipping {} event from log", event_type.name());
+                Ok(())
+            }
         }
     }
 
+    #[allow(dead_code)]
     async fn process_bucky_bank_created_event(&self, log: &str) -> Result<()> {
         debug!("Processing BuckyBankCreated event from log");
 
@@ -525,6 +940,22 @@ impl BuckyBankIndexer {
             deadline_ms: deadline_ms as i64,
             duration_days: duration_days as i64,
             current_balance: current_balance as i64,
+            mint: event_data
+                .get("mint")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            decimals: event_data
+                .get("decimals")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(9) as i16,
+            reward_amount: event_data
+                .get("reward_amount")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0) as i64,
+            bank_nonce: event_data
+                .get("bank_nonce")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0) as i64,
         };
 
         match self.db.save_bucky_bank_created_event(&new_event).await {
@@ -539,6 +970,7 @@ impl BuckyBankIndexer {
         }
     }
 
+    #[allow(dead_code)]
     async fn process_deposit_made_event(&self, log: &str) -> Result<()> {
         debug!("Processing DepositMade event from log");
 
@@ -569,6 +1001,18 @@ impl BuckyBankIndexer {
             amount: amount as i64,
             depositor: depositor.to_string(),
             created_at_ms: created_at_ms as i64,
+            mint: event_data
+                .get("mint")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            decimals: event_data
+                .get("decimals")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(9) as i16,
+            message: event_data
+                .get("message")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
         };
 
         match self.db.save_deposit_made_event(&new_event).await {
@@ -583,6 +1027,7 @@ impl BuckyBankIndexer {
         }
     }
 
+    #[allow(dead_code)]
     async fn process_withdrawal_requested_event(&self, log: &str) -> Result<()> {
         debug!("Processing EventWithdrawalRequested event from log");
 
@@ -623,6 +1068,8 @@ impl BuckyBankIndexer {
             1 => WithdrawalStatus::Approved,
             2 => WithdrawalStatus::Rejected,
             3 => WithdrawalStatus::Withdrawed,
+            4 => WithdrawalStatus::Cancelled,
+            5 => WithdrawalStatus::Expired,
             _ => WithdrawalStatus::Pending,
         };
 
@@ -646,6 +1093,25 @@ impl BuckyBankIndexer {
             approved_by: approved_by.unwrap_or_default(),
             created_at_ms: created_at_ms as i64,
             audit_at_ms: None,
+            mint: event_data
+                .get("mint")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            decimals: event_data
+                .get("decimals")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(9) as i16,
+            auto_approved: event_data
+                .get("auto_approved")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            expires_at_ms: event_data
+                .get("expires_at_ms")
+                .and_then(|v| v.as_i64()),
+            emergency: event_data
+                .get("emergency")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
             tx_digest: String::new(),
             event_seq: 0,
             timestamp_ms: 0,
@@ -663,18 +1129,21 @@ impl BuckyBankIndexer {
         }
     }
 
+    #[allow(dead_code)]
     async fn process_withdrawal_approved_event(&self, _log: &str) -> Result<()> {
         debug!("Processing EventWithdrawalApproved event from log");
         // 实现类似的逻辑
         Ok(())
     }
 
+    #[allow(dead_code)]
     async fn process_withdrawal_rejected_event(&self, _log: &str) -> Result<()> {
         debug!("Processing EventWithdrawalRejected event from log");
         // 实现类似的逻辑
         Ok(())
     }
 
+    #[allow(dead_code)]
     async fn process_event_withdrawed_event(&self, log: &str) -> Result<()> {
         debug!("Processing EventWithdrawalCompleted event from log");
 
@@ -717,6 +1186,22 @@ impl BuckyBankIndexer {
             left_balance: left_balance as i64,
             withdrawer: withdrawer.to_string(),
             created_at_ms: created_at_ms as i64,
+            mint: event_data
+                .get("mint")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            decimals: event_data
+                .get("decimals")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(9) as i16,
+            penalty_amount: event_data
+                .get("penalty_amount")
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0),
+            penalty_recipient: event_data
+                .get("penalty_recipient")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
         };
 
         match self.db.save_event_withdrawed_event(&new_event).await {
@@ -739,6 +1224,14 @@ impl BuckyBankIndexer {
         
         let event: BuckyBankCreatedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
         info!("[DEBUG] Deserialized BuckyBankCreated: {:?}", event);
+
+        let expected_id = derive_bucky_bank_address(&self.program_id, &event.parent, event.bank_nonce);
+        if expected_id != event.bucky_bank_id {
+            warn!(
+                "BuckyBank address {} does not match derived address {} (parent {}, nonce {})",
+                event.bucky_bank_id, expected_id, event.parent, event.bank_nonce
+            );
+        }
         
         let new_event = NewBuckyBankCreatedEvent {
             bucky_bank_id: event.bucky_bank_id.to_string(),
@@ -750,6 +1243,10 @@ impl BuckyBankIndexer {
             deadline_ms: event.deadline_ms as i64,
             duration_days: event.duration_days as i64,
             current_balance: event.current_balance as i64,
+            mint: event.mint.map(|m| m.to_string()),
+            decimals: event.decimals as i16,
+            reward_amount: event.reward_amount as i64,
+            bank_nonce: event.bank_nonce as i64,
         };
         
         self.db.save_bucky_bank_created_event(&new_event).await?;
@@ -770,6 +1267,9 @@ impl BuckyBankIndexer {
             amount: event.amount as i64,
             depositor: event.depositor.to_string(),
             created_at_ms: event.created_at_ms as i64,
+            mint: event.mint.map(|m| m.to_string()),
+            decimals: event.decimals as i16,
+            message: event.message,
         };
         
         self.db.save_deposit_made_event(&new_event).await?;
@@ -790,6 +1290,8 @@ impl BuckyBankIndexer {
             1 => "Approved",
             2 => "Rejected",
             3 => "Withdrawed",
+            4 => "Cancelled",
+            5 => "Expired",
             _ => "Pending",
         };
         
@@ -802,7 +1304,12 @@ impl BuckyBankIndexer {
             status: status_str.to_string(),
             approved_by: event.approved_by.to_string(),
             created_at_ms: event.created_at_ms as i64,
-            audit_at_ms: None,
+            audit_at_ms: event.auto_approved.then_some(event.created_at_ms as i64),
+            mint: event.mint.map(|m| m.to_string()),
+            decimals: event.decimals as i16,
+            auto_approved: event.auto_approved,
+            expires_at_ms: Some(event.expires_at_ms as i64),
+            emergency: event.emergency,
             tx_digest: String::new(),
             event_seq: 0,
             timestamp_ms: chrono::Utc::now().timestamp_millis(),
@@ -821,12 +1328,12 @@ impl BuckyBankIndexer {
         let event: EventWithdrawalApprovedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
         info!("[DEBUG] Deserialized EventWithdrawalApproved: {:?}", event);
         
-        // 更新数据库中的提现请求状态
-        self.db.update_withdrawal_request_status(
+        // 更新数据库中的提现请求状态和批准金额
+        self.db.approve_withdrawal_request(
             &event.request_id.to_string(),
-            &WithdrawalStatus::Approved,
-            Some(&event.approved_by.to_string()),
-            Some(chrono::Utc::now().timestamp_millis()),
+            &event.approved_by.to_string(),
+            event.approved_amount as i64,
+            chrono::Utc::now().timestamp_millis(),
         ).await?;
         
         info!("Updated withdrawal request status to Approved");
@@ -853,6 +1360,85 @@ impl BuckyBankIndexer {
         Ok(())
     }
 
+    async fn process_withdrawal_vote_cast_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: WithdrawalVoteCastEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized WithdrawalVoteCast: {:?}", event);
+
+        let new_vote = NewWithdrawalVote {
+            request_id: event.request_id.to_string(),
+            bucky_bank_id: event.bucky_bank_id.to_string(),
+            guardian: event.guardian.to_string(),
+            approve: event.approve,
+            reason: event.reason,
+            approvals: event.approvals as i16,
+            rejections: event.rejections as i16,
+            approval_threshold: event.approval_threshold as i16,
+            created_at_ms: event.created_at_ms as i64,
+        };
+
+        self.db.save_withdrawal_vote(&new_vote).await?;
+        info!("Saved WithdrawalVoteCast event to database");
+        Ok(())
+    }
+
+    async fn process_withdrawal_auto_approved_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: WithdrawalAutoApprovedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized WithdrawalAutoApproved: {:?}", event);
+
+        // 请求事件本身已携带自动批准标记，此处幂等地再同步一次
+        self.db.mark_withdrawal_request_auto_approved(
+            &event.request_id.to_string(),
+            event.created_at_ms as i64,
+        ).await?;
+
+        info!("Marked withdrawal request as auto-approved");
+        Ok(())
+    }
+
+    async fn process_withdrawal_cancelled_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: EventWithdrawalCancelledEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized EventWithdrawalCancelled: {:?}", event);
+
+        // 链上请求账户已关闭，索引中保留记录并标记为已取消
+        self.db.cancel_withdrawal_request(
+            &event.request_id.to_string(),
+            event.created_at_ms as i64,
+        ).await?;
+
+        info!("Updated withdrawal request status to Cancelled");
+        Ok(())
+    }
+
+    async fn process_withdrawal_request_expired_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: WithdrawalRequestExpiredEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized WithdrawalRequestExpired: {:?}", event);
+
+        // 链上请求账户已关闭，索引中保留记录并标记为已过期
+        self.db.expire_withdrawal_request(
+            &event.request_id.to_string(),
+            event.created_at_ms as i64,
+        ).await?;
+
+        info!("Updated withdrawal request status to Expired");
+        Ok(())
+    }
+
     async fn process_event_withdrawed_from_bytes(&self, bytes: &[u8]) -> Result<()> {
         if bytes.len() < 8 {
             return Err(anyhow::anyhow!("Event data too short"));
@@ -865,9 +1451,13 @@ impl BuckyBankIndexer {
             request_id: event.request_id.to_string(),
             bucky_bank_id: event.bucky_bank_id.to_string(),
             amount: event.amount as i64,
-            left_balance: 0, // 需要从合约获取
-            withdrawer: event.requester.to_string(),
+            left_balance: event.left_balance as i64,
+            withdrawer: event.withdrawer.to_string(),
             created_at_ms: event.created_at_ms as i64,
+            mint: event.mint.map(|m| m.to_string()),
+            decimals: event.decimals as i16,
+            penalty_amount: event.penalty_amount as i64,
+            penalty_recipient: event.penalty_recipient.map(|p| p.to_string()),
         };
         
         self.db.save_event_withdrawed_event(&new_event).await?;
@@ -875,6 +1465,382 @@ impl BuckyBankIndexer {
         Ok(())
     }
 
+    async fn process_balance_reconciled_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: BalanceReconciledEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized BalanceReconciled: {:?}", event);
+
+        // 以链上对账结果覆盖索引中的余额
+        self.db.update_bucky_bank_balance(
+            &event.bucky_bank_id.to_string(),
+            event.current_balance as i64,
+        ).await?;
+
+        info!("Updated BuckyBank balance from BalanceReconciled event");
+        Ok(())
+    }
+
+    async fn process_bucky_bank_settled_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: BuckyBankSettledEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized BuckyBankSettled: {:?}", event);
+
+        // 以结算结果作为存钱罐最终状态
+        self.db.update_bucky_bank_status(
+            &event.bucky_bank_id.to_string(),
+            event.status.as_str(),
+            event.current_balance as i64,
+            event.created_at_ms as i64,
+        ).await?;
+
+        info!("Updated BuckyBank status from BuckyBankSettled event");
+        Ok(())
+    }
+
+    async fn process_bucky_bank_closed_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: BuckyBankClosedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized BuckyBankClosed: {:?}", event);
+
+        self.db.close_bucky_bank(
+            &event.bucky_bank_id.to_string(),
+            &event.recipient.to_string(),
+            event.created_at_ms as i64,
+        ).await?;
+
+        info!("Marked BuckyBank as Closed from BuckyBankClosed event");
+        Ok(())
+    }
+
+    async fn process_bucky_bank_config_updated_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: BuckyBankConfigUpdatedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized BuckyBankConfigUpdated: {:?}", event);
+
+        let change = NewBuckyBankConfigChange {
+            bucky_bank_id: event.bucky_bank_id.to_string(),
+            parent: event.parent.to_string(),
+            old_name: event.old_name,
+            new_name: event.new_name,
+            old_target_amount: event.old_target_amount as i64,
+            new_target_amount: event.new_target_amount as i64,
+            old_deadline_ms: event.old_deadline_ms as i64,
+            new_deadline_ms: event.new_deadline_ms as i64,
+            old_status: event.old_status.as_str().to_string(),
+            new_status: event.new_status.as_str().to_string(),
+            created_at_ms: event.created_at_ms as i64,
+        };
+
+        self.db.save_bucky_bank_config_change(&change).await?;
+        info!("Saved BuckyBankConfigUpdated event to database");
+        Ok(())
+    }
+
+    async fn process_parent_transferred_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: ParentTransferredEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized ParentTransferred: {:?}", event);
+
+        self.db.update_bucky_bank_parent(
+            &event.bucky_bank_id.to_string(),
+            &event.new_parent.to_string(),
+        ).await?;
+
+        info!("Updated BuckyBank parent from ParentTransferred event");
+        Ok(())
+    }
+
+    async fn process_child_rotated_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: ChildRotatedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized ChildRotated: {:?}", event);
+
+        self.db.update_bucky_bank_child(
+            &event.bucky_bank_id.to_string(),
+            &event.new_child.to_string(),
+        ).await?;
+
+        info!("Updated BuckyBank child from ChildRotated event");
+        Ok(())
+    }
+
+    async fn process_match_credited_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: MatchCreditedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized MatchCredited: {:?}", event);
+
+        let credit = NewMatchCredit {
+            bucky_bank_id: event.bucky_bank_id.to_string(),
+            parent: event.parent.to_string(),
+            depositor: event.depositor.to_string(),
+            deposit_amount: event.deposit_amount as i64,
+            match_amount: event.match_amount as i64,
+            credited_amount: event.credited_amount as i64,
+            skipped: event.skipped,
+            match_vault_balance: event.match_vault_balance as i64,
+            created_at_ms: event.created_at_ms as i64,
+            mint: event.mint.map(|m| m.to_string()),
+            decimals: event.decimals as i16,
+        };
+
+        self.db.save_match_credit(&credit).await?;
+        info!("Saved MatchCredited event to database");
+        Ok(())
+    }
+
+    async fn process_interest_accrued_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: InterestAccruedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized InterestAccrued: {:?}", event);
+
+        let accrual = NewInterestAccrual {
+            bucky_bank_id: event.bucky_bank_id.to_string(),
+            interest_rate_bps: event.interest_rate_bps as i32,
+            current_balance: event.current_balance as i64,
+            interest_amount: event.interest_amount as i64,
+            credited_amount: event.credited_amount as i64,
+            skipped: event.skipped,
+            interest_pending: event.interest_pending as i64,
+            interest_vault_balance: event.interest_vault_balance as i64,
+            total_interest_earned: event.total_interest_earned as i64,
+            accrued_by: event.accrued_by.to_string(),
+            created_at_ms: event.created_at_ms as i64,
+            mint: event.mint.map(|m| m.to_string()),
+            decimals: event.decimals as i16,
+        };
+
+        self.db.save_interest_accrual(&accrual).await?;
+        info!("Saved InterestAccrued event to database");
+        Ok(())
+    }
+
+    async fn process_milestone_reached_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: MilestoneReachedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized MilestoneReached: {:?}", event);
+
+        let milestone = NewMilestoneReached {
+            bucky_bank_id: event.bucky_bank_id.to_string(),
+            milestone_index: event.milestone_index as i16,
+            amount: event.amount as i64,
+            label: event.label,
+            current_balance: event.current_balance as i64,
+            target_amount: event.target_amount as i64,
+            reached_by: event.reached_by.to_string(),
+            created_at_ms: event.created_at_ms as i64,
+            mint: event.mint.map(|m| m.to_string()),
+            decimals: event.decimals as i16,
+        };
+
+        self.db.save_milestone_reached(&milestone).await?;
+        info!("Saved MilestoneReached event to database");
+        Ok(())
+    }
+
+    async fn process_achievement_unlocked_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: AchievementUnlockedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized AchievementUnlocked: {:?}", event);
+
+        let achievement = NewAchievementUnlocked {
+            child: event.child.to_string(),
+            bucky_bank_id: event.bucky_bank_id.to_string(),
+            badge: event.badge.as_str().to_string(),
+            current_streak_weeks: event.current_streak_weeks as i32,
+            longest_streak_weeks: event.longest_streak_weeks as i32,
+            current_balance: event.current_balance as i64,
+            target_amount: event.target_amount as i64,
+            created_at_ms: event.created_at_ms as i64,
+        };
+
+        self.db.save_achievement_unlocked(&achievement).await?;
+        info!("Saved AchievementUnlocked event to database");
+        Ok(())
+    }
+
+    async fn process_bucky_bank_frozen_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: BuckyBankFrozenEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized BuckyBankFrozen: {:?}", event);
+        warn!("BuckyBank {} frozen by parent {}: {}", event.bucky_bank_id, event.parent, event.reason);
+
+        let action = NewBankEmergencyAction {
+            bucky_bank_id: event.bucky_bank_id.to_string(),
+            parent: event.parent.to_string(),
+            action: "Frozen".to_string(),
+            reason: event.reason,
+            destination: None,
+            amount: None,
+            left_balance: None,
+            frozen: true,
+            created_at_ms: event.created_at_ms as i64,
+            mint: None,
+            decimals: None,
+        };
+
+        self.db.save_bank_emergency_action(&action).await?;
+        info!("Saved BuckyBankFrozen event to database");
+        Ok(())
+    }
+
+    async fn process_bucky_bank_unfrozen_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: BuckyBankUnfrozenEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized BuckyBankUnfrozen: {:?}", event);
+
+        let action = NewBankEmergencyAction {
+            bucky_bank_id: event.bucky_bank_id.to_string(),
+            parent: event.parent.to_string(),
+            action: "Unfrozen".to_string(),
+            reason: event.reason,
+            destination: None,
+            amount: None,
+            left_balance: None,
+            frozen: false,
+            created_at_ms: event.created_at_ms as i64,
+            mint: None,
+            decimals: None,
+        };
+
+        self.db.save_bank_emergency_action(&action).await?;
+        info!("Saved BuckyBankUnfrozen event to database");
+        Ok(())
+    }
+
+    async fn process_emergency_withdrawal_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: EmergencyWithdrawalEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized EmergencyWithdrawal: {:?}", event);
+        warn!(
+            "Emergency withdrawal of {} from BuckyBank {} to {} by parent {}: {}",
+            event.amount, event.bucky_bank_id, event.destination, event.parent, event.reason
+        );
+
+        let action = NewBankEmergencyAction {
+            bucky_bank_id: event.bucky_bank_id.to_string(),
+            parent: event.parent.to_string(),
+            action: "EmergencyWithdrawal".to_string(),
+            reason: event.reason,
+            destination: Some(event.destination.to_string()),
+            amount: Some(event.amount as i64),
+            left_balance: Some(event.left_balance as i64),
+            frozen: event.frozen,
+            created_at_ms: event.created_at_ms as i64,
+            mint: event.mint.map(|m| m.to_string()),
+            decimals: Some(event.decimals as i16),
+        };
+
+        self.db.save_bank_emergency_action(&action).await?;
+        info!("Saved EmergencyWithdrawal event to database");
+        Ok(())
+    }
+
+    async fn process_global_stats_updated_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: GlobalStatsUpdatedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("Deserialized GlobalStatsUpdated: {:?}", event);
+
+        let delta = GlobalStats {
+            total_bucky_banks: event.total_bucky_banks,
+            active_bucky_banks: event.active_bucky_banks,
+            completed_bucky_banks: event.completed_bucky_banks,
+            failed_bucky_banks: event.failed_bucky_banks,
+            closed_bucky_banks: event.closed_bucky_banks,
+            total_deposits: event.total_deposits,
+            total_deposit_amount: event.total_deposit_amount,
+            total_withdrawals: event.total_withdrawals,
+            total_withdrawal_amount: event.total_withdrawal_amount,
+            total_value_locked: event.total_value_locked,
+            updated_at_ms: event.updated_at_ms as i64,
+        };
+
+        self.db.apply_global_stats_delta(&delta).await?;
+        info!("Applied GlobalStatsUpdated delta from BuckyBank {}", event.bucky_bank_id);
+        Ok(())
+    }
+
+    async fn process_saving_reward_updated_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: SavingRewardUpdatedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized SavingRewardUpdated: {:?}", event);
+
+        self.db.update_bucky_bank_reward_amount(
+            &event.bucky_bank_id.to_string(),
+            event.new_reward_amount as i64,
+        ).await?;
+
+        info!("Updated BuckyBank reward amount from SavingRewardUpdated event");
+        Ok(())
+    }
+
+    async fn process_reward_claimed_from_bytes(&self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() < 8 {
+            return Err(anyhow::anyhow!("Event data too short"));
+        }
+
+        let event: RewardClaimedEvent = BorshDeserialize::deserialize(&mut &bytes[8..])?;
+        info!("[DEBUG] Deserialized RewardClaimed: {:?}", event);
+
+        let new_event = NewRewardClaimedEvent {
+            bucky_bank_id: event.bucky_bank_id.to_string(),
+            child: event.child.to_string(),
+            amount: event.amount as i64,
+            created_at_ms: event.created_at_ms as i64,
+            mint: event.mint.map(|m| m.to_string()),
+            decimals: event.decimals as i16,
+        };
+
+        self.db.save_reward_claimed_event(&new_event).await?;
+        info!("Saved RewardClaimed event to database");
+        Ok(())
+    }
+
+    #[allow(dead_code)]
     fn extract_event_data(&self, log: &str, event_name: &str) -> Result<serde_json::Value> {
         // 从日志中提取 JSON 数据
         // 格式: "Program log: <event_name>: <json_data>"
@@ -889,3 +1855,104 @@ impl BuckyBankIndexer {
         Err(anyhow::anyhow!("Failed to extract event data from log: {}", log))
     }
 }
+
+/// 从交易日志中找出指定类型的全部事件数据（base64）
+///
+/// 从匹配的指令日志一直扫描到本程序的下一条指令，同一指令发出的多个同类型事件都会返回；
+/// 按判别符筛选，其他类型的事件和 CPI 日志会被跳过。
+fn find_event_data<'a>(log_messages: &'a [String], event_type: &EventType) -> Vec<&'a str> {
+    let mut event_data = Vec::new();
+    let mut i = 0;
+
+    while i < log_messages.len() {
+        if !event_type.matches_log(&log_messages[i]) {
+            i += 1;
+            continue;
+        }
+
+        let mut j = i + 1;
+        while j < log_messages.len() {
+            let next_log = &log_messages[j];
+            if let Some(data_str) = next_log.strip_prefix("Program data: ") {
+                if event_type.matches_program_data(data_str) {
+                    event_data.push(data_str);
+                }
+            } else if next_log.contains("Instruction: ")
+                && EventType::all_event_types()
+                    .iter()
+                    .any(|t| t.matches_log(next_log))
+            {
+                // 已到本程序的下一条指令（Token 程序 CPI 的指令日志不会匹配，继续扫描）
+                break;
+            }
+            j += 1;
+        }
+
+        // 从下一条指令继续匹配
+        i = j;
+    }
+
+    event_data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM: &str = "Program BuckyBank1111111111111111111111111111111";
+
+    fn program_data(event_type: &EventType, payload: u8) -> String {
+        let mut bytes = event_type.discriminator().to_vec();
+        bytes.push(payload);
+        format!("Program data: {}", base64::engine::general_purpose::STANDARD.encode(bytes))
+    }
+
+    fn payloads(event_data: &[&str]) -> Vec<u8> {
+        event_data
+            .iter()
+            .map(|data| base64::engine::general_purpose::STANDARD.decode(data).unwrap()[8])
+            .collect()
+    }
+
+    #[test]
+    fn finds_every_event_emitted_by_one_instruction() {
+        let logs = vec![
+            format!("{} invoke [1]", PROGRAM),
+            "Program log: Instruction: Deposit".to_string(),
+            "Program 11111111111111111111111111111111 invoke [2]".to_string(),
+            "Program 11111111111111111111111111111111 success".to_string(),
+            program_data(&EventType::DepositMade, 0),
+            program_data(&EventType::MilestoneReached, 1),
+            program_data(&EventType::AchievementUnlocked, 2),
+            program_data(&EventType::MilestoneReached, 3),
+            program_data(&EventType::AchievementUnlocked, 4),
+            program_data(&EventType::MilestoneReached, 5),
+            format!("{} success", PROGRAM),
+        ];
+
+        assert_eq!(payloads(&find_event_data(&logs, &EventType::MilestoneReached)), vec![1, 3, 5]);
+        assert_eq!(payloads(&find_event_data(&logs, &EventType::AchievementUnlocked)), vec![2, 4]);
+        assert_eq!(payloads(&find_event_data(&logs, &EventType::DepositMade)), vec![0]);
+    }
+
+    #[test]
+    fn stops_at_the_next_program_instruction() {
+        let logs = vec![
+            format!("{} invoke [1]", PROGRAM),
+            "Program log: Instruction: Deposit".to_string(),
+            program_data(&EventType::MilestoneReached, 1),
+            format!("{} success", PROGRAM),
+            format!("{} invoke [1]", PROGRAM),
+            "Program log: Instruction: Withdraw".to_string(),
+            program_data(&EventType::MilestoneReached, 2),
+            format!("{} success", PROGRAM),
+            format!("{} invoke [1]", PROGRAM),
+            "Program log: Instruction: Deposit".to_string(),
+            program_data(&EventType::MilestoneReached, 3),
+            program_data(&EventType::MilestoneReached, 4),
+            format!("{} success", PROGRAM),
+        ];
+
+        assert_eq!(payloads(&find_event_data(&logs, &EventType::MilestoneReached)), vec![1, 3, 4]);
+    }
+}